@@ -2,6 +2,14 @@
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
+///
+/// Widgets receive a [`Clipboard`] in [`Widget::on_event`], which can be read
+/// synchronously while handling the event. This way, a paste shortcut can
+/// insert the contents at the current cursor position in the same update,
+/// instead of waiting for an asynchronous `clipboard::read` command to
+/// complete.
+///
+/// [`Widget::on_event`]: crate::Widget::on_event
 pub trait Clipboard {
     /// Reads the current content of the [`Clipboard`] as text.
     fn read(&self, kind: Kind) -> Option<String>;
@@ -3,6 +3,7 @@ use crate::keyboard;
 use crate::mouse;
 use crate::touch;
 use crate::window;
+use crate::SmolStr;
 
 /// A user interface event.
 ///
@@ -33,6 +34,9 @@ pub enum Event {
 pub enum PlatformSpecific {
     /// A MacOS specific event
     MacOS(MacOS),
+
+    /// A keyboard event carrying raw, platform-dependent key information
+    Keyboard(Keyboard),
 }
 
 /// Describes an event specific to MacOS
@@ -46,6 +50,52 @@ pub enum MacOS {
     ReceivedUrl(String),
 }
 
+/// Describes a keyboard event carrying raw, platform-dependent key information
+///
+/// These events are produced alongside the regular [`keyboard::Event`] and are
+/// useful when the physical location of a key matters more than its meaning
+/// (e.g. movement keys in games).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Keyboard {
+    /// A physical key was pressed.
+    KeyPressed {
+        /// The platform-dependent scancode of the physical key.
+        scancode: u32,
+
+        /// The text the key produces on the current keyboard layout, ignoring
+        /// any modifiers, if any.
+        text: Option<SmolStr>,
+    },
+
+    /// A physical key was released.
+    KeyReleased {
+        /// The platform-dependent scancode of the physical key.
+        scancode: u32,
+
+        /// The text the key produces on the current keyboard layout, ignoring
+        /// any modifiers, if any.
+        text: Option<SmolStr>,
+    },
+
+    /// A physical key is being held down and started repeating.
+    ///
+    /// This event is produced right before the first repeated
+    /// [`Keyboard::KeyPressed`] of the key.
+    RepeatStarted {
+        /// The platform-dependent scancode of the physical key.
+        scancode: u32,
+    },
+
+    /// A physical key that was repeating has been released.
+    ///
+    /// This event is produced right before the [`Keyboard::KeyReleased`] of
+    /// the key.
+    RepeatEnded {
+        /// The platform-dependent scancode of the physical key.
+        scancode: u32,
+    },
+}
+
 /// The status of an [`Event`] after being processed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -4,8 +4,10 @@ pub mod key;
 mod event;
 mod location;
 mod modifiers;
+mod pressed_keys;
 
 pub use event::Event;
 pub use key::Key;
 pub use location::Location;
 pub use modifiers::Modifiers;
+pub use pressed_keys::PressedKeys;
@@ -10,6 +10,10 @@ use crate::SmolStr;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Event {
     /// A keyboard key was pressed.
+    ///
+    /// Modifier keys produce this event too, alongside
+    /// [`Event::ModifiersChanged`], which allows binding a bare tap of a
+    /// modifier (e.g. pressing and releasing `Control` alone).
     KeyPressed {
         /// The key pressed.
         key: Key,
@@ -25,6 +29,19 @@ pub enum Event {
     },
 
     /// A keyboard key was released.
+    ///
+    /// Like [`Event::KeyPressed`], this is produced for modifier keys too.
+    ///
+    /// Releasing a key does not produce any text, since text input only
+    /// happens on presses (and their repeats). The [`Key`] still tells which
+    /// character was released.
+    ///
+    /// On desktop platforms (Windows, macOS, Linux and the BSDs), the
+    /// `KeyReleased` variant of [`crate::event::Keyboard`] is produced
+    /// alongside this event. Its text is the text of the physical key
+    /// ignoring any modifiers, so it may differ from the text of the press
+    /// (e.g. `a` instead of `A` when `Shift` is held). It is not produced on
+    /// other platforms.
     KeyReleased {
         /// The key released.
         key: Key,
@@ -0,0 +1,15 @@
+use crate::keyboard::Modifiers;
+
+/// The keys currently held down in a window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PressedKeys {
+    /// The platform-dependent scancodes of the physical keys held down, in
+    /// the order they were pressed.
+    ///
+    /// These are the same scancodes carried by the platform specific
+    /// keyboard events.
+    pub scancodes: Vec<u32>,
+
+    /// The state of the modifier keys.
+    pub modifiers: Modifiers,
+}
@@ -9,5 +9,5 @@ mod interaction;
 pub use button::Button;
 pub use click::Click;
 pub use cursor::Cursor;
-pub use event::{Event, ScrollDelta};
+pub use event::{Event, ScrollDelta, ScrollSource};
 pub use interaction::Interaction;
@@ -32,6 +32,9 @@ pub enum Event {
     WheelScrolled {
         /// The scroll movement.
         delta: ScrollDelta,
+
+        /// The device that produced the scroll movement.
+        source: ScrollSource,
     },
 }
 
@@ -54,3 +57,23 @@ pub enum ScrollDelta {
         y: f32,
     },
 }
+
+/// The source of a scroll movement.
+///
+/// Useful to apply a different sensitivity depending on the device used
+/// to scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollSource {
+    /// A mouse wheel with discrete steps.
+    #[default]
+    Wheel,
+
+    /// Fingers on a touchpad or touchscreen.
+    Finger,
+
+    /// A continuous device without discrete steps, like a trackball.
+    ///
+    /// _**Note:** The `winit` shell cannot tell these devices apart, so it
+    /// never produces this source._
+    Continuous,
+}
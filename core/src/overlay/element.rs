@@ -170,8 +170,9 @@ where
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
             ) {
-                self.operation.focusable(state, id);
+                self.operation.focusable(state, id, bounds);
             }
 
             fn scrollable(
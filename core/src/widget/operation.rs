@@ -29,7 +29,16 @@ pub trait Operation<T> {
     );
 
     /// Operates on a widget that can be focused.
-    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+    ///
+    /// The `bounds` of the widget can be used to traverse focusable widgets
+    /// in visual order, instead of tree order.
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
 
     /// Operates on a widget that can be scrolled.
     fn scrollable(
@@ -136,8 +145,9 @@ where
                     &mut self,
                     state: &mut dyn Focusable,
                     id: Option<&Id>,
+                    bounds: Rectangle,
                 ) {
-                    self.operation.focusable(state, id);
+                    self.operation.focusable(state, id, bounds);
                 }
 
                 fn text_input(
@@ -161,8 +171,13 @@ where
             .container(id, bounds, operate_on_children);
         }
 
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-            self.operation.focusable(state, id);
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
         }
 
         fn scrollable(
@@ -32,7 +32,12 @@ pub fn focus<T>(target: Id) -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for Focus {
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match id {
                 Some(id) if id == &self.target => {
                     state.focus();
@@ -71,7 +76,12 @@ where
     where
         O: Operation<T> + 'static,
     {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if state.is_focused() {
                 self.count.focused = Some(self.count.total);
             }
@@ -109,7 +119,12 @@ pub fn focus_previous<T>() -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for FocusPrevious {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if self.count.total == 0 {
                 return;
             }
@@ -149,7 +164,12 @@ pub fn focus_next<T>() -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for FocusNext {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match self.count.focused {
                 None if self.current == 0 => state.focus(),
                 Some(focused) if focused == self.current => state.unfocus(),
@@ -173,6 +193,189 @@ pub fn focus_next<T>() -> impl Operation<T> {
     count(|count| FocusNext { count, current: 0 })
 }
 
+/// Produces an [`Operation`] that searches for the current focused widget, and
+/// - if found, focuses the previous focusable widget, wrapping around to the
+///   last one when the first one is focused.
+/// - if not found, focuses the last focusable widget.
+pub fn focus_previous_wrapping<T>() -> impl Operation<T> {
+    count(|count| {
+        FocusIndex::new(match count.focused {
+            None | Some(0) => count.total.checked_sub(1),
+            Some(focused) => Some(focused - 1),
+        })
+    })
+}
+
+/// Produces an [`Operation`] that searches for the current focused widget, and
+/// - if found, focuses the next focusable widget, wrapping around to the
+///   first one when the last one is focused.
+/// - if not found, focuses the first focusable widget.
+pub fn focus_next_wrapping<T>() -> impl Operation<T> {
+    count(|count| {
+        FocusIndex::new(match count.focused {
+            None => (count.total > 0).then_some(0),
+            Some(focused) => Some((focused + 1) % count.total),
+        })
+    })
+}
+
+/// Produces an [`Operation`] that searches for the current focused widget, and
+/// - if found, focuses the previous focusable widget in reading order.
+/// - if not found, focuses the last focusable widget in reading order.
+///
+/// See [`focus_next_in_reading_order`] for details about reading order.
+pub fn focus_previous_in_reading_order<T>() -> impl Operation<T> {
+    focus_in_reading_order(|focused, total| match focused {
+        None => total.checked_sub(1),
+        Some(focused) => focused.checked_sub(1),
+    })
+}
+
+/// Produces an [`Operation`] that searches for the current focused widget, and
+/// - if found, focuses the next focusable widget in reading order.
+/// - if not found, focuses the first focusable widget in reading order.
+///
+/// Reading order sorts focusable widgets by the position of their bounds:
+/// from top to bottom and, for widgets in the same row, from left to right.
+/// Widgets whose bounds overlap vertically are considered to be in the same
+/// row, so items of different heights centered in a row keep their order.
+/// This is useful when the order of the widget tree does not match its
+/// visual layout (e.g. in a wrapped grid).
+pub fn focus_next_in_reading_order<T>() -> impl Operation<T> {
+    focus_in_reading_order(|focused, total| match focused {
+        None => (total > 0).then_some(0),
+        Some(focused) => (focused + 1 < total).then_some(focused + 1),
+    })
+}
+
+/// Produces an [`Operation`] that collects the bounds of every focusable
+/// widget and focuses the one chosen by `next`.
+///
+/// The `next` function receives the index of the focused widget in reading
+/// order, if any, and the total amount of focusable widgets. It returns the
+/// index in reading order of the widget to focus, if any.
+fn focus_in_reading_order<T>(
+    next: fn(Option<usize>, usize) -> Option<usize>,
+) -> impl Operation<T> {
+    struct Collect {
+        bounds: Vec<Rectangle>,
+        focused: Option<usize>,
+        next: fn(Option<usize>, usize) -> Option<usize>,
+    }
+
+    impl<T> Operation<T> for Collect {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            if state.is_focused() {
+                self.focused = Some(self.bounds.len());
+            }
+
+            self.bounds.push(bounds);
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            let mut by_top: Vec<usize> = (0..self.bounds.len()).collect();
+
+            by_top.sort_by(|&a, &b| {
+                self.bounds[a].y.total_cmp(&self.bounds[b].y)
+            });
+
+            // Group the widgets into rows of vertically overlapping bounds
+            let mut rows: Vec<(f32, Vec<usize>)> = Vec::new();
+
+            for i in by_top {
+                let bounds = self.bounds[i];
+                let bottom = bounds.y + bounds.height;
+
+                match rows.last_mut() {
+                    Some((row_bottom, row)) if bounds.y < *row_bottom => {
+                        *row_bottom = row_bottom.max(bottom);
+                        row.push(i);
+                    }
+                    _ => rows.push((bottom, vec![i])),
+                }
+            }
+
+            let order: Vec<usize> = rows
+                .into_iter()
+                .flat_map(|(_, mut row)| {
+                    row.sort_by(|&a, &b| {
+                        self.bounds[a].x.total_cmp(&self.bounds[b].x)
+                    });
+
+                    row
+                })
+                .collect();
+
+            let focused = self
+                .focused
+                .and_then(|focused| order.iter().position(|&i| i == focused));
+
+            let target = (self.next)(focused, order.len()).map(|i| order[i]);
+
+            Outcome::Chain(Box::new(FocusIndex::new(target)))
+        }
+    }
+
+    Collect {
+        bounds: Vec::new(),
+        focused: None,
+        next,
+    }
+}
+
+/// An [`Operation`] that focuses the focusable widget at the `target` index,
+/// in tree order, and unfocuses every other one.
+struct FocusIndex {
+    target: Option<usize>,
+    current: usize,
+}
+
+impl FocusIndex {
+    fn new(target: Option<usize>) -> Self {
+        Self { target, current: 0 }
+    }
+}
+
+impl<T> Operation<T> for FocusIndex {
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+        if self.target == Some(self.current) {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+}
+
 /// Produces an [`Operation`] that searches for the current focused widget
 /// and stores its ID. This ignores widgets that do not have an ID.
 pub fn find_focused() -> impl Operation<Id> {
@@ -181,7 +384,12 @@ pub fn find_focused() -> impl Operation<Id> {
     }
 
     impl Operation<Id> for FindFocused {
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if state.is_focused() && id.is_some() {
                 self.focused = id.cloned();
             }
@@ -207,3 +415,171 @@ pub fn find_focused() -> impl Operation<Id> {
 
     FindFocused { focused: None }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point, Size};
+
+    #[derive(Debug, Default)]
+    struct State {
+        is_focused: bool,
+    }
+
+    impl Focusable for State {
+        fn is_focused(&self) -> bool {
+            self.is_focused
+        }
+
+        fn focus(&mut self) {
+            self.is_focused = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.is_focused = false;
+        }
+    }
+
+    /// A 2x2 grid of buttons whose tree order is column by column.
+    fn grid() -> Vec<(State, Rectangle)> {
+        let size = Size::new(80.0, 30.0);
+
+        [(0.0, 0.0), (0.0, 40.0), (100.0, 0.0), (100.0, 40.0)]
+            .into_iter()
+            .map(|(x, y)| {
+                (State::default(), Rectangle::new(Point::new(x, y), size))
+            })
+            .collect()
+    }
+
+    fn run(
+        operation: impl Operation<()> + 'static,
+        widgets: &mut [(State, Rectangle)],
+    ) {
+        let mut operation: Box<dyn Operation<()>> = Box::new(operation);
+
+        loop {
+            operation.container(None, Rectangle::default(), &mut |operation| {
+                for (state, bounds) in widgets.iter_mut() {
+                    operation.focusable(state, None, *bounds);
+                }
+            });
+
+            match operation.finish() {
+                Outcome::Chain(next) => {
+                    operation = next;
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn focused(widgets: &[(State, Rectangle)]) -> Option<usize> {
+        widgets.iter().position(|(state, _)| state.is_focused)
+    }
+
+    #[test]
+    fn focus_next_in_reading_order_follows_rows() {
+        let mut widgets = grid();
+
+        let focus_order: Vec<_> = (0..5)
+            .map(|_| {
+                run(focus_next_in_reading_order(), &mut widgets);
+
+                focused(&widgets)
+            })
+            .collect();
+
+        assert_eq!(focus_order, [Some(0), Some(2), Some(1), Some(3), None]);
+    }
+
+    #[test]
+    fn focus_next_in_reading_order_keeps_centered_rows() {
+        // A centered row of widgets with different heights, followed by a
+        // second row
+        let mut widgets: Vec<_> = [
+            (0.0, 10.0, 10.0),
+            (100.0, 0.0, 30.0),
+            (200.0, 5.0, 20.0),
+            (0.0, 40.0, 30.0),
+        ]
+        .into_iter()
+        .map(|(x, y, height)| {
+            (
+                State::default(),
+                Rectangle::new(Point::new(x, y), Size::new(80.0, height)),
+            )
+        })
+        .collect();
+
+        let focus_order: Vec<_> = (0..4)
+            .map(|_| {
+                run(focus_next_in_reading_order(), &mut widgets);
+
+                focused(&widgets)
+            })
+            .collect();
+
+        assert_eq!(focus_order, [Some(0), Some(1), Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn focus_previous_in_reading_order_follows_rows() {
+        let mut widgets = grid();
+
+        let focus_order: Vec<_> = (0..5)
+            .map(|_| {
+                run(focus_previous_in_reading_order(), &mut widgets);
+
+                focused(&widgets)
+            })
+            .collect();
+
+        assert_eq!(focus_order, [Some(3), Some(1), Some(2), Some(0), None]);
+    }
+
+    #[test]
+    fn focus_next_follows_tree_order() {
+        let mut widgets = grid();
+
+        run(focus_next(), &mut widgets);
+        run(focus_next(), &mut widgets);
+
+        assert_eq!(focused(&widgets), Some(1));
+    }
+
+    #[test]
+    fn focus_next_wrapping_returns_to_first() {
+        let mut widgets: Vec<_> = (0..3)
+            .map(|i| {
+                (
+                    State::default(),
+                    Rectangle::new(
+                        Point::new(0.0, i as f32 * 40.0),
+                        Size::new(80.0, 30.0),
+                    ),
+                )
+            })
+            .collect();
+
+        let focus_order: Vec<_> = (0..4)
+            .map(|_| {
+                run(focus_next_wrapping(), &mut widgets);
+
+                focused(&widgets)
+            })
+            .collect();
+
+        assert_eq!(focus_order, [Some(0), Some(1), Some(2), Some(0)]);
+    }
+
+    #[test]
+    fn focus_previous_wrapping_returns_to_last() {
+        let mut widgets = grid();
+
+        run(focus_next(), &mut widgets);
+        run(focus_previous_wrapping(), &mut widgets);
+
+        assert_eq!(focused(&widgets), Some(3));
+    }
+}
@@ -19,6 +19,11 @@ pub enum Event {
         size: Size,
     },
 
+    /// A window could not be opened.
+    ///
+    /// The reason of the failure is provided by the platform.
+    OpenFailed(String),
+
     /// A window was closed.
     Closed,
 
@@ -38,6 +43,18 @@ pub enum Event {
         height: u32,
     },
 
+    /// The scale factor of a window changed (e.g. it was moved to a monitor
+    /// with a different DPI).
+    ///
+    /// The physical size of the window may change, too, which is reported by
+    /// a [`Event::Resized`] right after this event.
+    Rescaled {
+        /// The previous scale factor of the window.
+        previous_scale_factor: f64,
+        /// The new scale factor of the window.
+        scale_factor: f64,
+    },
+
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
@@ -47,11 +64,25 @@ pub enum Event {
     CloseRequested,
 
     /// A window was focused.
+    ///
+    /// This is produced when the window system activates the window, which
+    /// normally, but not always, means it receives keyboard input. Use it to
+    /// style the window as active (e.g. a highlighted titlebar), and rely on
+    /// keyboard events to track keyboard input.
     Focused,
 
     /// A window was unfocused.
+    ///
+    /// See [`Event::Focused`] for details.
     Unfocused,
 
+    /// A window became fully hidden from view (e.g. covered by other windows
+    /// or moved to an inactive workspace), or visible again.
+    ///
+    /// Applications can use it to pause expensive animations while the window
+    /// can't be seen. Not every platform reports it.
+    Occluded(bool),
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
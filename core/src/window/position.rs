@@ -14,6 +14,10 @@ pub enum Position {
     /// position. So if you have decorations enabled and want the window to be
     /// at (0, 0) you would have to set the position to
     /// `(PADDING_X, PADDING_Y)`.
+    ///
+    /// ## Platform-specific
+    /// - **Wayland:** Unsupported. The compositor decides where windows are
+    ///   placed, so the position is ignored.
     Specific(Point),
 }
 
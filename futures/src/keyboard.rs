@@ -59,3 +59,27 @@ where
         }
     })
 }
+
+/// Listens to changes of the keyboard modifiers and calls the given function
+/// to map the new [`Modifiers`] into actual messages.
+///
+/// Unlike key presses, modifier changes are always listened to, even if a
+/// widget captured the event.
+pub fn on_modifiers_change<Message>(
+    f: fn(Modifiers) -> Message,
+) -> Subscription<Message>
+where
+    Message: MaybeSend + 'static,
+{
+    #[derive(Hash)]
+    struct OnModifiersChange;
+
+    subscription::filter_map((OnModifiersChange, f), move |event, _status| {
+        match event {
+            core::Event::Keyboard(Event::ModifiersChanged(modifiers)) => {
+                Some(f(modifiers))
+            }
+            _ => None,
+        }
+    })
+}
@@ -14,6 +14,10 @@ pub enum Action<T> {
 
     /// Write the given contents to the clipboard.
     Write(String, Kind),
+
+    /// Query whether the application currently owns the clipboard and
+    /// produce `T` with the result.
+    IsOwner(Box<dyn Fn(bool) -> T>, Kind),
 }
 
 impl<T> Action<T> {
@@ -30,6 +34,9 @@ impl<T> Action<T> {
                 Action::Read(Box::new(move |s| f(o(s))), target)
             }
             Self::Write(content, target) => Action::Write(content, target),
+            Self::IsOwner(o, target) => {
+                Action::IsOwner(Box::new(move |s| f(o(s))), target)
+            }
         }
     }
 }
@@ -39,6 +46,9 @@ impl<T> fmt::Debug for Action<T> {
         match self {
             Self::Read(_, target) => write!(f, "Action::Read{target:?}"),
             Self::Write(_, target) => write!(f, "Action::Write({target:?})"),
+            Self::IsOwner(_, target) => {
+                write!(f, "Action::IsOwner({target:?})")
+            }
         }
     }
 }
@@ -78,3 +88,37 @@ pub fn write_primary<Message>(contents: String) -> Command<Message> {
         Kind::Primary,
     )))
 }
+
+/// Query whether the application currently owns the contents of the
+/// clipboard.
+///
+/// Ownership is inferred by comparing the contents last written by the
+/// application with the current contents of the clipboard, since the
+/// platforms do not report who wrote them. If another application (e.g. a
+/// clipboard manager) writes the very same text afterwards, the application
+/// is still reported as the owner.
+pub fn is_owner<Message>(
+    f: impl Fn(bool) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::IsOwner(
+        Box::new(f),
+        Kind::Standard,
+    )))
+}
+
+/// Query whether the application currently owns the contents of the primary
+/// clipboard.
+///
+/// Ownership is inferred by comparing the contents last written by the
+/// application with the current contents of the clipboard, since the
+/// platforms do not report who wrote them. If another application (e.g. a
+/// clipboard manager) writes the very same text afterwards, the application
+/// is still reported as the owner.
+pub fn is_owner_primary<Message>(
+    f: impl Fn(bool) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::IsOwner(
+        Box::new(f),
+        Kind::Primary,
+    )))
+}
@@ -7,11 +7,12 @@ pub use action::Action;
 pub use screenshot::Screenshot;
 
 use crate::command::{self, Command};
+use crate::core::keyboard;
 use crate::core::time::Instant;
 use crate::core::window::{
     Event, Icon, Id, Level, Mode, Settings, UserAttention,
 };
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::futures::event;
 use crate::futures::Subscription;
 
@@ -61,6 +62,25 @@ pub fn resize<Message>(id: Id, new_size: Size) -> Command<Message> {
     Command::single(command::Action::Window(Action::Resize(id, new_size)))
 }
 
+/// Makes the window keep the given aspect ratio (width divided by height)
+/// while resized, or stop keeping any if `None`.
+///
+/// Aspect ratios that are not finite or not positive are invalid, and the
+/// resulting [`Command`] does nothing.
+pub fn change_aspect_ratio<Message>(
+    id: Id,
+    aspect_ratio: Option<f32>,
+) -> Command<Message> {
+    if aspect_ratio.is_some_and(|ratio| !ratio.is_finite() || ratio <= 0.0) {
+        return Command::none();
+    }
+
+    Command::single(command::Action::Window(Action::ChangeAspectRatio(
+        id,
+        aspect_ratio,
+    )))
+}
+
 /// Fetches the window's size in logical dimensions.
 pub fn fetch_size<Message>(
     id: Id,
@@ -117,6 +137,18 @@ pub fn move_to<Message>(id: Id, position: Point) -> Command<Message> {
     Command::single(command::Action::Window(Action::Move(id, position)))
 }
 
+/// Tells the input method where the text cursor is, in logical coordinates
+/// relative to the window, so its candidate popup appears next to it.
+///
+/// _**Note:** The shell does not enable input methods for its windows yet, so
+/// this has no effect until they are enabled._
+pub fn set_ime_cursor_area<Message>(
+    id: Id,
+    area: Rectangle,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetImeCursorArea(id, area)))
+}
+
 /// Changes the [`Mode`] of the window.
 pub fn change_mode<Message>(id: Id, mode: Mode) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeMode(id, mode)))
@@ -130,6 +162,21 @@ pub fn fetch_mode<Message>(
     Command::single(command::Action::Window(Action::FetchMode(id, Box::new(f))))
 }
 
+/// Fetches the keys currently held down in the window, together with the
+/// state of the modifier keys.
+///
+/// Only the keys pressed while the window is focused are tracked, and they
+/// are all released when the window loses focus.
+pub fn fetch_pressed_keys<Message>(
+    id: Id,
+    f: impl FnOnce(keyboard::PressedKeys) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchPressedKeys(
+        id,
+        Box::new(f),
+    )))
+}
+
 /// Toggles the window to maximized or back.
 pub fn toggle_maximize<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::ToggleMaximize(id)))
@@ -173,11 +220,33 @@ pub fn change_level<Message>(id: Id, level: Level) -> Command<Message> {
 
 /// Show the [system menu] at cursor position.
 ///
+/// ## Platform-specific
+/// Android / iOS / macOS / Orbital / Web / X11: Unsupported.
+///
 /// [system menu]: https://en.wikipedia.org/wiki/Common_menus_in_Microsoft_Windows#System_menu
 pub fn show_system_menu<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::ShowSystemMenu(id)))
 }
 
+/// Show the [system menu] at the given logical position, relative to the
+/// window.
+///
+/// This is useful to open the menu from a custom title bar (e.g. with a
+/// right click).
+///
+/// ## Platform-specific
+/// Android / iOS / macOS / Orbital / Web / X11: Unsupported.
+///
+/// [system menu]: https://en.wikipedia.org/wiki/Common_menus_in_Microsoft_Windows#System_menu
+pub fn show_system_menu_at<Message>(
+    id: Id,
+    position: Point,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ShowSystemMenuAt(
+        id, position,
+    )))
+}
+
 /// Fetches an identifier unique to the window, provided by the underlying windowing system. This is
 /// not to be confused with [`Id`].
 pub fn fetch_id<Message>(
@@ -187,6 +256,13 @@ pub fn fetch_id<Message>(
     Command::single(command::Action::Window(Action::FetchId(id, Box::new(f))))
 }
 
+/// Fetches the [`Id`] of every window currently open.
+pub fn fetch_all<Message>(
+    f: impl FnOnce(Vec<Id>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchAll(Box::new(f))))
+}
+
 /// Changes the [`Icon`] of the window.
 pub fn change_icon<Message>(id: Id, icon: Icon) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeIcon(id, icon)))
@@ -205,6 +281,20 @@ pub fn run_with_handle<Message>(
     )))
 }
 
+/// Makes the window click-through, so mouse events pass through it to
+/// whatever is behind.
+pub fn enable_mouse_passthrough<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::EnableMousePassthrough(id)))
+}
+
+/// Makes the window receive mouse events again, after
+/// [`enable_mouse_passthrough`].
+pub fn disable_mouse_passthrough<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::DisableMousePassthrough(
+        id,
+    )))
+}
+
 /// Captures a [`Screenshot`] from the window.
 pub fn screenshot<Message>(
     id: Id,
@@ -1,5 +1,6 @@
+use crate::core::keyboard;
 use crate::core::window::{Icon, Id, Level, Mode, Settings, UserAttention};
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::futures::MaybeSend;
 use crate::window::Screenshot;
 
@@ -21,6 +22,13 @@ pub enum Action<T> {
     Drag(Id),
     /// Resize the window to the given logical dimensions.
     Resize(Id, Size),
+    /// Keep the given aspect ratio (width divided by height) while the window
+    /// is resized, or stop keeping any if `None`.
+    ///
+    /// This is best-effort, since the window is only asked to resize back to
+    /// the closest valid size after every resize. Maximized and fullscreen
+    /// windows are not constrained.
+    ChangeAspectRatio(Id, Option<f32>),
     /// Fetch the current logical dimensions of the window.
     FetchSize(Id, Box<dyn FnOnce(Size) -> T + 'static>),
     /// Fetch if the current window is maximized or not.
@@ -44,10 +52,25 @@ pub enum Action<T> {
     ///
     /// Unsupported on Wayland.
     Move(Id, Point),
+    /// Set the area of the window, in logical coordinates, where the text
+    /// cursor of the focused text field is, so the input method can place its
+    /// candidate popup next to it.
+    ///
+    /// Input methods are not enabled by the shell yet, so this has no effect
+    /// until they are.
+    ///
+    /// ## Platform-specific
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    SetImeCursorArea(Id, Rectangle),
     /// Change the [`Mode`] of the window.
     ChangeMode(Id, Mode),
     /// Fetch the current [`Mode`] of the window.
     FetchMode(Id, Box<dyn FnOnce(Mode) -> T + 'static>),
+    /// Fetch the keys currently held down in the window.
+    ///
+    /// ## Platform-specific
+    /// - **iOS / Android / Web:** No scancodes are reported.
+    FetchPressedKeys(Id, Box<dyn FnOnce(keyboard::PressedKeys) -> T + 'static>),
     /// Toggle the window to maximized or back
     ToggleMaximize(Id),
     /// Toggle whether window has decorations.
@@ -88,8 +111,16 @@ pub enum Action<T> {
     /// ## Platform-specific
     /// Android / iOS / macOS / Orbital / Web / X11: Unsupported.
     ShowSystemMenu(Id),
+    /// Show the system menu at the given logical position, relative to the
+    /// window.
+    ///
+    /// ## Platform-specific
+    /// Android / iOS / macOS / Orbital / Web / X11: Unsupported.
+    ShowSystemMenuAt(Id, Point),
     /// Fetch the raw identifier unique to the window.
     FetchId(Id, Box<dyn FnOnce(u64) -> T + 'static>),
+    /// Fetch the [`Id`] of every window currently open.
+    FetchAll(Box<dyn FnOnce(Vec<Id>) -> T + 'static>),
     /// Change the window [`Icon`].
     ///
     /// On Windows and X11, this is typically the small icon in the top-left
@@ -107,6 +138,18 @@ pub enum Action<T> {
     ChangeIcon(Id, Icon),
     /// Runs the closure with the native window handle of the window with the given [`Id`].
     RunWithHandle(Id, Box<dyn FnOnce(&WindowHandle<'_>) -> T + 'static>),
+    /// Set the window to be click-through, so mouse events pass through it to
+    /// whatever is behind.
+    ///
+    /// ## Platform-specific
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    EnableMousePassthrough(Id),
+    /// Make the window receive mouse events again, after
+    /// [`Action::EnableMousePassthrough`].
+    ///
+    /// ## Platform-specific
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    DisableMousePassthrough(Id),
     /// Screenshot the viewport of the window.
     Screenshot(Id, Box<dyn FnOnce(Screenshot) -> T + 'static>),
 }
@@ -125,6 +168,9 @@ impl<T> Action<T> {
             Self::Close(id) => Action::Close(id),
             Self::Drag(id) => Action::Drag(id),
             Self::Resize(id, size) => Action::Resize(id, size),
+            Self::ChangeAspectRatio(id, aspect_ratio) => {
+                Action::ChangeAspectRatio(id, aspect_ratio)
+            }
             Self::FetchSize(id, o) => {
                 Action::FetchSize(id, Box::new(move |s| f(o(s))))
             }
@@ -140,10 +186,16 @@ impl<T> Action<T> {
                 Action::FetchPosition(id, Box::new(move |s| f(o(s))))
             }
             Self::Move(id, position) => Action::Move(id, position),
+            Self::SetImeCursorArea(id, area) => {
+                Action::SetImeCursorArea(id, area)
+            }
             Self::ChangeMode(id, mode) => Action::ChangeMode(id, mode),
             Self::FetchMode(id, o) => {
                 Action::FetchMode(id, Box::new(move |s| f(o(s))))
             }
+            Self::FetchPressedKeys(id, o) => {
+                Action::FetchPressedKeys(id, Box::new(move |s| f(o(s))))
+            }
             Self::ToggleMaximize(id) => Action::ToggleMaximize(id),
             Self::ToggleDecorations(id) => Action::ToggleDecorations(id),
             Self::RequestUserAttention(id, attention_type) => {
@@ -152,13 +204,23 @@ impl<T> Action<T> {
             Self::GainFocus(id) => Action::GainFocus(id),
             Self::ChangeLevel(id, level) => Action::ChangeLevel(id, level),
             Self::ShowSystemMenu(id) => Action::ShowSystemMenu(id),
+            Self::ShowSystemMenuAt(id, position) => {
+                Action::ShowSystemMenuAt(id, position)
+            }
             Self::FetchId(id, o) => {
                 Action::FetchId(id, Box::new(move |s| f(o(s))))
             }
+            Self::FetchAll(o) => Action::FetchAll(Box::new(move |s| f(o(s)))),
             Self::ChangeIcon(id, icon) => Action::ChangeIcon(id, icon),
             Self::RunWithHandle(id, o) => {
                 Action::RunWithHandle(id, Box::new(move |s| f(o(s))))
             }
+            Self::EnableMousePassthrough(id) => {
+                Action::EnableMousePassthrough(id)
+            }
+            Self::DisableMousePassthrough(id) => {
+                Action::DisableMousePassthrough(id)
+            }
             Self::Screenshot(id, tag) => Action::Screenshot(
                 id,
                 Box::new(move |screenshot| f(tag(screenshot))),
@@ -178,6 +240,9 @@ impl<T> fmt::Debug for Action<T> {
             Self::Resize(id, size) => {
                 write!(f, "Action::Resize({id:?}, {size:?})")
             }
+            Self::ChangeAspectRatio(id, aspect_ratio) => {
+                write!(f, "Action::ChangeAspectRatio({id:?}, {aspect_ratio:?})")
+            }
             Self::FetchSize(id, _) => write!(f, "Action::FetchSize({id:?})"),
             Self::FetchMaximized(id, _) => {
                 write!(f, "Action::FetchMaximized({id:?})")
@@ -197,10 +262,16 @@ impl<T> fmt::Debug for Action<T> {
             Self::Move(id, position) => {
                 write!(f, "Action::Move({id:?}, {position})")
             }
+            Self::SetImeCursorArea(id, area) => {
+                write!(f, "Action::SetImeCursorArea({id:?}, {area:?})")
+            }
             Self::ChangeMode(id, mode) => {
                 write!(f, "Action::SetMode({id:?}, {mode:?})")
             }
             Self::FetchMode(id, _) => write!(f, "Action::FetchMode({id:?})"),
+            Self::FetchPressedKeys(id, _) => {
+                write!(f, "Action::FetchPressedKeys({id:?})")
+            }
             Self::ToggleMaximize(id) => {
                 write!(f, "Action::ToggleMaximize({id:?})")
             }
@@ -217,13 +288,23 @@ impl<T> fmt::Debug for Action<T> {
             Self::ShowSystemMenu(id) => {
                 write!(f, "Action::ShowSystemMenu({id:?})")
             }
+            Self::ShowSystemMenuAt(id, position) => {
+                write!(f, "Action::ShowSystemMenuAt({id:?}, {position:?})")
+            }
             Self::FetchId(id, _) => write!(f, "Action::FetchId({id:?})"),
+            Self::FetchAll(_) => write!(f, "Action::FetchAll"),
             Self::ChangeIcon(id, _icon) => {
                 write!(f, "Action::ChangeIcon({id:?})")
             }
             Self::RunWithHandle(id, _) => {
                 write!(f, "Action::RunWithHandle({id:?})")
             }
+            Self::EnableMousePassthrough(id) => {
+                write!(f, "Action::EnableMousePassthrough({id:?})")
+            }
+            Self::DisableMousePassthrough(id) => {
+                write!(f, "Action::DisableMousePassthrough({id:?})")
+            }
             Self::Screenshot(id, _) => write!(f, "Action::Screenshot({id:?})"),
         }
     }
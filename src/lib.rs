@@ -213,7 +213,7 @@ pub use crate::core::{
 pub mod clipboard {
     //! Access the clipboard.
     pub use crate::runtime::clipboard::{
-        read, read_primary, write, write_primary,
+        is_owner, is_owner_primary, read, read_primary, write, write_primary,
     };
 }
 
@@ -242,21 +242,27 @@ pub mod font {
 
 pub mod event {
     //! Handle events of a user interface.
-    pub use crate::core::event::{Event, MacOS, PlatformSpecific, Status};
+    pub use crate::core::event::{
+        Event, Keyboard, MacOS, PlatformSpecific, Status,
+    };
     pub use iced_futures::event::{listen, listen_raw, listen_with};
 }
 
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::key;
-    pub use crate::core::keyboard::{Event, Key, Location, Modifiers};
-    pub use iced_futures::keyboard::{on_key_press, on_key_release};
+    pub use crate::core::keyboard::{
+        Event, Key, Location, Modifiers, PressedKeys,
+    };
+    pub use iced_futures::keyboard::{
+        on_key_press, on_key_release, on_modifiers_change,
+    };
 }
 
 pub mod mouse {
     //! Listen and react to mouse events.
     pub use crate::core::mouse::{
-        Button, Cursor, Event, Interaction, ScrollDelta,
+        Button, Cursor, Event, Interaction, ScrollDelta, ScrollSource,
     };
 }
 
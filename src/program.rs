@@ -252,6 +252,8 @@ impl<P: Definition> Program<P> {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing,
+            key_repeat: settings.key_repeat,
+            touch_as_mouse: settings.touch_as_mouse,
         })
     }
 
@@ -271,6 +273,28 @@ impl<P: Definition> Program<P> {
         }
     }
 
+    /// Sets the [`Settings::key_repeat`] of the [`Program`].
+    pub fn key_repeat(self, key_repeat: bool) -> Self {
+        Self {
+            settings: Settings {
+                key_repeat,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::touch_as_mouse`] of the [`Program`].
+    pub fn touch_as_mouse(self, touch_as_mouse: bool) -> Self {
+        Self {
+            settings: Settings {
+                touch_as_mouse,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Program`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
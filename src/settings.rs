@@ -48,6 +48,26 @@ pub struct Settings<Flags = ()> {
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
+
+    /// Whether keys held down should repeat.
+    ///
+    /// When disabled, holding a key down produces a single key press. This
+    /// also stops focus navigation from repeating while `Tab` is held.
+    ///
+    /// By default, it is enabled.
+    pub key_repeat: bool,
+
+    /// Whether the primary touch point should also produce mouse events.
+    ///
+    /// This lets widgets that only handle the mouse work on a touchscreen.
+    /// Widgets that handle touch events already (e.g. a [`Button`]) may react
+    /// twice to the same interaction, so it should only be enabled when
+    /// needed.
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`Button`]: crate::widget::Button
+    pub touch_as_mouse: bool,
 }
 
 impl<Flags> Settings<Flags> {
@@ -65,6 +85,8 @@ impl<Flags> Settings<Flags> {
             default_font: default_settings.default_font,
             default_text_size: default_settings.default_text_size,
             antialiasing: default_settings.antialiasing,
+            key_repeat: default_settings.key_repeat,
+            touch_as_mouse: default_settings.touch_as_mouse,
         }
     }
 }
@@ -82,6 +104,8 @@ where
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
+            key_repeat: true,
+            touch_as_mouse: false,
         }
     }
 }
@@ -93,6 +117,8 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             window: settings.window,
             flags: settings.flags,
             fonts: settings.fonts,
+            key_repeat: settings.key_repeat,
+            touch_as_mouse: settings.touch_as_mouse,
         }
     }
 }
@@ -1,17 +1,27 @@
 //! Allow your users to perform actions by pressing a button.
+use crate::container;
+use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::gradient;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
-    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
-    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+    Alignment, Background, Border, Clipboard, Color, Element, Gradient, Layout,
+    Length, Padding, Pixels, Rectangle, Shadow, Shell, Size, Theme, Vector,
+    Widget,
 };
+use crate::runtime::Command;
 
 /// A generic widget that produces a message when pressed.
 ///
@@ -45,18 +55,46 @@ use crate::core::{
 ///     disabled_button().on_press(Message::ButtonPressed)
 /// }
 /// ```
+///
+/// The content of a [`Button`] is diffed against its previous widget tree like
+/// any other child, so a label that is rebuilt on every view (e.g. a counter)
+/// keeps its state as long as its widget stays the same:
+///
+/// ```no_run
+/// # type Button<'a, Message> = iced_widget::Button<'a, Message>;
+/// # use iced_widget::text;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     ClearItems,
+/// }
+///
+/// fn clear_button<'a>(items: usize) -> Button<'a, Message> {
+///     Button::new(text(format!("{items} items")))
+///         .on_press(Message::ClearItems)
+/// }
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
     Renderer: crate::core::Renderer,
     Theme: Catalog,
 {
+    id: Option<Id>,
     content: Element<'a, Message, Theme, Renderer>,
-    on_press: Option<Message>,
+    disabled_content: Option<Element<'a, Message, Theme, Renderer>>,
+    on_press: Option<OnPress<'a, Message>>,
+    on_press_key: Option<(keyboard::Key, keyboard::Modifiers)>,
     width: Length,
     height: Length,
+    min_width: f32,
     padding: Padding,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
     clip: bool,
+    is_active: bool,
+    transition: Option<Duration>,
+    mouse_buttons: &'a [mouse::Button],
     class: Theme::Class<'a>,
 }
 
@@ -73,16 +111,44 @@ where
         let size = content.as_widget().size_hint();
 
         Button {
+            id: None,
             content,
+            disabled_content: None,
             on_press: None,
+            on_press_key: None,
             width: size.width.fluid(),
             height: size.height.fluid(),
+            min_width: 0.0,
             padding: DEFAULT_PADDING,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
             clip: false,
+            is_active: false,
+            transition: None,
+            mouse_buttons: &[mouse::Button::Left],
             class: Theme::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the content shown instead of the regular one while the [`Button`]
+    /// is disabled.
+    ///
+    /// The [`Button`] is sized to fit both contents, so enabling or disabling
+    /// it does not change its layout.
+    pub fn disabled_content(
+        mut self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.disabled_content = Some(content.into());
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -95,17 +161,69 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Button`].
+    ///
+    /// The [`Button`] will never shrink below this width, which is useful to
+    /// keep a row of buttons with different contents uniform.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Button`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
         self
     }
 
+    /// Sets the content alignment for the horizontal axis of the [`Button`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the content alignment for the vertical axis of the [`Button`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
-    /// Unless `on_press` is called, the [`Button`] will be disabled.
+    /// Unless `on_press` is called, the [`Button`] will be disabled. A
+    /// [`disable`] command takes precedence over this handler.
     pub fn on_press(mut self, on_press: Message) -> Self {
-        self.on_press = Some(on_press);
+        self.on_press = Some(OnPress::Direct(on_press));
+        self
+    }
+
+    /// Sets the closure that will produce the message when the [`Button`] is
+    /// pressed.
+    ///
+    /// The closure is only called when the [`Button`] is actually pressed,
+    /// which avoids building an expensive message on every `view`. Since a
+    /// [`Button`] can be pressed many times, the closure must be [`Fn`].
+    ///
+    /// Unless `on_press_with` or [`Button::on_press`] are called, the
+    /// [`Button`] will be disabled.
+    pub fn on_press_with(
+        mut self,
+        on_press: impl Fn() -> Message + 'a,
+    ) -> Self {
+        self.on_press = Some(OnPress::Closure(Box::new(on_press)));
+        self
+    }
+
+    /// Sets the closure that will produce the message when the [`Button`] is
+    /// pressed, given the [`keyboard::Modifiers`] held at press time.
+    ///
+    /// This is useful to react differently to modified presses (e.g.
+    /// opening a link in the background on `Ctrl`-click).
+    pub fn on_press_with_modifiers(
+        mut self,
+        on_press: impl Fn(keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_press = Some(OnPress::WithModifiers(Box::new(on_press)));
         self
     }
 
@@ -114,17 +232,72 @@ where
     ///
     /// If `None`, the [`Button`] will be disabled.
     pub fn on_press_maybe(mut self, on_press: Option<Message>) -> Self {
-        self.on_press = on_press;
+        self.on_press = on_press.map(OnPress::Direct);
+        self
+    }
+
+    /// Sets the [`keyboard::Key`] that presses the [`Button`] while focused.
+    ///
+    /// The key only presses the [`Button`] when no modifiers are held. A
+    /// focused [`Button`] can always be pressed with `Enter` or `Space`, too,
+    /// unless the command or alt modifiers are held. Both the main and the
+    /// numpad `Enter` keys are accepted.
+    pub fn on_press_key(self, key: keyboard::Key) -> Self {
+        self.on_press_key_with_modifiers(key, keyboard::Modifiers::empty())
+    }
+
+    /// Sets the [`keyboard::Key`] and the exact [`keyboard::Modifiers`] that
+    /// press the [`Button`] while focused.
+    pub fn on_press_key_with_modifiers(
+        mut self,
+        key: keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> Self {
+        self.on_press_key = Some((key, modifiers));
         self
     }
 
     /// Sets whether the contents of the [`Button`] should be clipped on
     /// overflow.
+    ///
+    /// Clipping only affects drawing, so toggling it does not change the
+    /// layout of the [`Button`]. The contents are clipped to the rectangular
+    /// bounds of the [`Button`], ignoring the radius of its border.
     pub fn clip(mut self, clip: bool) -> Self {
         self.clip = clip;
         self
     }
 
+    /// Sets whether the [`Button`] is latched as active.
+    ///
+    /// An active [`Button`] is drawn as pressed regardless of the mouse and
+    /// focus, which is useful for toggle buttons (e.g. in a toggle group).
+    pub fn active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+
+    /// Sets the [`Duration`] of the transition between the active and hovered
+    /// styles of the [`Button`].
+    ///
+    /// By default, the style of a [`Button`] changes instantly.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
+    /// Sets the mouse buttons that can press the [`Button`].
+    ///
+    /// Events of any other mouse button will be ignored by the [`Button`] and
+    /// propagate to the widgets behind it.
+    ///
+    /// By default, only [`mouse::Button::Left`] presses a [`Button`]. A press
+    /// is only completed by releasing the same mouse button that started it.
+    pub fn mouse_buttons(mut self, mouse_buttons: &'a [mouse::Button]) -> Self {
+        self.mouse_buttons = mouse_buttons;
+        self
+    }
+
     /// Sets the style of the [`Button`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -142,11 +315,106 @@ where
         self.class = class.into();
         self
     }
+
+    /// Returns the [`OnPress`] handler of the [`Button`], unless it has been
+    /// disabled with a [`disable`] command.
+    fn on_press_of(&self, state: &State) -> Option<&OnPress<'a, Message>> {
+        if state.is_disabled {
+            None
+        } else {
+            self.on_press.as_ref()
+        }
+    }
+
+    /// Returns the index of the [`Tree`] of the content currently shown.
+    fn visible_index(&self, state: &State) -> usize {
+        if self.on_press_of(state).is_none() && self.disabled_content.is_some()
+        {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the content currently shown.
+    fn visible_content(
+        &self,
+        state: &State,
+    ) -> &Element<'a, Message, Theme, Renderer> {
+        match &self.disabled_content {
+            Some(disabled_content) if self.on_press_of(state).is_none() => {
+                disabled_content
+            }
+            _ => &self.content,
+        }
+    }
+
+    /// Returns the content currently shown, mutably.
+    fn visible_content_mut(
+        &mut self,
+        state: &State,
+    ) -> &mut Element<'a, Message, Theme, Renderer> {
+        let is_disabled = self.on_press_of(state).is_none();
+
+        match &mut self.disabled_content {
+            Some(disabled_content) if is_disabled => disabled_content,
+            _ => &mut self.content,
+        }
+    }
+}
+
+enum OnPress<'a, Message> {
+    Direct(Message),
+    Closure(Box<dyn Fn() -> Message + 'a>),
+    WithModifiers(Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>),
+}
+
+impl<'a, Message: Clone> OnPress<'a, Message> {
+    fn get(&self, modifiers: keyboard::Modifiers) -> Message {
+        match self {
+            OnPress::Direct(message) => message.clone(),
+            OnPress::Closure(f) => f(),
+            OnPress::WithModifiers(f) => f(modifiers),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_pressed: bool,
+    pressed_by: Option<mouse::Button>,
+    is_focused: bool,
+    modifiers: keyboard::Modifiers,
+    is_hovered: bool,
+    hover_changed_at: Option<Instant>,
+    now: Option<Instant>,
+    is_disabled: bool,
+}
+
+/// Returns the mouse button of a press or release event, or `None` for touch
+/// events.
+fn pressed_by(event: &Event) -> Option<mouse::Button> {
+    match event {
+        Event::Mouse(
+            mouse::Event::ButtonPressed(button)
+            | mouse::Event::ButtonReleased(button),
+        ) => Some(*button),
+        _ => None,
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -165,11 +433,23 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.content)]
+        let mut children = vec![Tree::new(&self.content)];
+
+        if let Some(disabled_content) = &self.disabled_content {
+            children.push(Tree::new(disabled_content));
+        }
+
+        children
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(std::slice::from_ref(&self.content));
+        match &self.disabled_content {
+            Some(disabled_content) => tree.diff_children(&[
+                self.content.as_widget(),
+                disabled_content.as_widget(),
+            ]),
+            None => tree.diff_children(std::slice::from_ref(&self.content)),
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -185,16 +465,60 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::padded(
-            limits,
+        let state = *tree.state.downcast_ref::<State>();
+
+        // The minimum width only applies to the button, since its content is
+        // laid out with loose limits
+        let mut limits = limits.min_width(self.min_width);
+
+        // The hidden content is measured, too, so the size of the button does
+        // not change when it is enabled or disabled
+        if let Some(disabled_content) = &self.disabled_content {
+            let (hidden, index) = if self.visible_index(&state) == 0 {
+                (disabled_content, 1)
+            } else {
+                (&self.content, 0)
+            };
+
+            let size = hidden
+                .as_widget()
+                .layout(
+                    &mut tree.children[index],
+                    renderer,
+                    &limits.shrink(self.padding).loose(),
+                )
+                .size();
+
+            // Only a shrinking button grows to fit it, so a fixed size is
+            // always honored
+            if self.width == Length::Shrink {
+                limits =
+                    limits.min_width(size.width + self.padding.horizontal());
+            }
+
+            if self.height == Length::Shrink {
+                limits =
+                    limits.min_height(size.height + self.padding.vertical());
+            }
+        }
+
+        layout::positioned(
+            &limits,
             self.width,
             self.height,
             self.padding,
             |limits| {
-                self.content.as_widget().layout(
-                    &mut tree.children[0],
+                self.visible_content(&state).as_widget().layout(
+                    &mut tree.children[self.visible_index(&state)],
                     renderer,
-                    limits,
+                    &limits.loose(),
+                )
+            },
+            |content, size| {
+                content.align(
+                    Alignment::from(self.horizontal_alignment),
+                    Alignment::from(self.vertical_alignment),
+                    size,
                 )
             },
         )
@@ -207,14 +531,36 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(None, layout.bounds(), &mut |operation| {
-            self.content.as_widget().operate(
-                &mut tree.children[0],
-                layout.children().next().unwrap(),
-                renderer,
-                operation,
+        let state = tree.state.downcast_mut::<State>();
+
+        // A disabled button cannot be activated, so it is skipped when
+        // moving the focus and it loses any focus it had
+        if self.on_press_of(state).is_some() {
+            operation.focusable(
+                state,
+                self.id.as_ref().map(|id| &id.0),
+                layout.bounds(),
             );
-        });
+        } else {
+            state.is_focused = false;
+        }
+
+        operation.custom(state, self.id.as_ref().map(|id| &id.0));
+
+        let state = *state;
+
+        operation.container(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &mut |operation| {
+                self.visible_content(&state).as_widget().operate(
+                    &mut tree.children[self.visible_index(&state)],
+                    layout.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            },
+        );
     }
 
     fn on_event(
@@ -228,57 +574,152 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        if let event::Status::Captured = self.content.as_widget_mut().on_event(
-            &mut tree.children[0],
-            event.clone(),
-            layout.children().next().unwrap(),
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        ) {
+        let mut state = *tree.state.downcast_ref::<State>();
+
+        // A disabled button loses its focus, so it is not activated when it
+        // is enabled again
+        if state.is_focused && self.on_press_of(&state).is_none() {
+            state.is_focused = false;
+
+            tree.state.downcast_mut::<State>().is_focused = false;
+        }
+
+        let index = self.visible_index(&state);
+
+        if let event::Status::Captured =
+            self.visible_content_mut(&state).as_widget_mut().on_event(
+                &mut tree.children[index],
+                event.clone(),
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            )
+        {
             return event::Status::Captured;
         }
 
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            Event::Mouse(
+                mouse::Event::ButtonPressed(button)
+                | mouse::Event::ButtonReleased(button),
+            ) if !self.mouse_buttons.contains(&button) => {}
+            Event::Mouse(mouse::Event::ButtonPressed(_))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
+                let is_enabled = self.on_press_of(&state).is_some();
+                let state = tree.state.downcast_mut::<State>();
+
+                if is_enabled {
                     let bounds = layout.bounds();
 
                     if cursor.is_over(bounds) {
-                        let state = tree.state.downcast_mut::<State>();
-
-                        state.is_pressed = true;
+                        // Only the first button of a press releases it
+                        if !state.is_pressed {
+                            state.is_pressed = true;
+                            state.pressed_by = pressed_by(&event);
+                        }
 
                         return event::Status::Captured;
                     }
                 }
+
+                state.is_focused = false;
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            Event::Mouse(mouse::Event::ButtonReleased(_))
             | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if let Some(on_press) = self.on_press.clone() {
-                    let state = tree.state.downcast_mut::<State>();
-
-                    if state.is_pressed {
-                        state.is_pressed = false;
+                if state.is_pressed && state.pressed_by == pressed_by(&event) {
+                    // The press is released even if the button has been
+                    // disabled in the meantime
+                    tree.state.downcast_mut::<State>().is_pressed = false;
 
+                    if let Some(on_press) = self.on_press_of(&state) {
                         let bounds = layout.bounds();
 
                         if cursor.is_over(bounds) {
-                            shell.publish(on_press);
+                            shell.publish(on_press.get(state.modifiers));
                         }
 
                         return event::Status::Captured;
                     }
                 }
             }
-            Event::Touch(touch::Event::FingerLost { .. }) => {
+            Event::Touch(touch::Event::FingerLost { .. })
+            | Event::Window(_, window::Event::Unfocused) => {
                 let state = tree.state.downcast_mut::<State>();
 
                 state.is_pressed = false;
             }
+            Event::Mouse(
+                mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft,
+            ) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                // The release of a press dragged out of the window may never
+                // be received
+                if matches!(event, Event::Mouse(mouse::Event::CursorLeft)) {
+                    state.is_pressed = false;
+                }
+
+                let is_hovered = cursor.is_over(layout.bounds());
+
+                if self.transition.is_some() && state.is_hovered != is_hovered {
+                    state.is_hovered = is_hovered;
+                    state.hover_changed_at = Some(Instant::now());
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.now = Some(now);
+
+                if let (Some(transition), Some(hover_changed_at)) =
+                    (self.transition, state.hover_changed_at)
+                {
+                    if now < hover_changed_at + transition {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                if let Some(on_press) = self.on_press_of(&state) {
+                    // Named keys are matched regardless of their location,
+                    // so the numpad `Enter` activates the button, too.
+                    // Combinations with command modifiers are left to the
+                    // shortcuts of the application.
+                    let is_activation_key = (matches!(
+                        key,
+                        keyboard::Key::Named(
+                            key::Named::Enter | key::Named::Space
+                        )
+                    ) && !modifiers.command()
+                        && !modifiers.alt())
+                        || self.on_press_key.as_ref().is_some_and(
+                            |(press_key, press_modifiers)| {
+                                *press_key == key
+                                    && *press_modifiers == modifiers
+                            },
+                        );
+
+                    if state.is_focused && is_activation_key {
+                        shell.publish(on_press.get(modifiers));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -299,21 +740,50 @@ where
         let content_layout = layout.children().next().unwrap();
         let is_mouse_over = cursor.is_over(bounds);
 
-        let status = if self.on_press.is_none() {
+        let state = tree.state.downcast_ref::<State>();
+
+        let status = if self.on_press_of(state).is_none() {
             Status::Disabled
+        } else if self.is_active {
+            Status::Pressed
         } else if is_mouse_over {
-            let state = tree.state.downcast_ref::<State>();
-
             if state.is_pressed {
                 Status::Pressed
             } else {
                 Status::Hovered
             }
+        } else if state.is_focused {
+            Status::Focused
         } else {
             Status::Active
         };
 
-        let style = theme.style(&self.class, status);
+        let style = match (self.transition, state.hover_changed_at, state.now) {
+            (Some(transition), Some(hover_changed_at), Some(now))
+                if matches!(status, Status::Active | Status::Hovered)
+                    && !state.is_focused
+                    && state.is_hovered == is_mouse_over =>
+            {
+                let progress = (now
+                    .saturating_duration_since(hover_changed_at)
+                    .as_secs_f32()
+                    / transition.as_secs_f32())
+                .min(1.0);
+
+                let (from, to) = if state.is_hovered {
+                    (Status::Active, Status::Hovered)
+                } else {
+                    (Status::Hovered, Status::Active)
+                };
+
+                mix(
+                    theme.style(&self.class, from),
+                    theme.style(&self.class, to),
+                    progress,
+                )
+            }
+            _ => theme.style(&self.class, status),
+        };
 
         if style.background.is_some()
             || style.border.width > 0.0
@@ -337,8 +807,8 @@ where
             *viewport
         };
 
-        self.content.as_widget().draw(
-            &tree.children[0],
+        self.visible_content(state).as_widget().draw(
+            &tree.children[self.visible_index(state)],
             renderer,
             theme,
             &renderer::Style {
@@ -352,15 +822,16 @@ where
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let is_mouse_over = cursor.is_over(layout.bounds());
+        let state = tree.state.downcast_ref::<State>();
 
-        if is_mouse_over && self.on_press.is_some() {
+        if is_mouse_over && self.on_press_of(state).is_some() {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -374,8 +845,11 @@ where
         renderer: &Renderer,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        self.content.as_widget_mut().overlay(
-            &mut tree.children[0],
+        let state = *tree.state.downcast_ref::<State>();
+        let index = self.visible_index(&state);
+
+        self.visible_content_mut(&state).as_widget_mut().overlay(
+            &mut tree.children[index],
             layout.children().next().unwrap(),
             renderer,
             translation,
@@ -395,6 +869,102 @@ where
     }
 }
 
+/// The identifier of a [`Button`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`Button`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// Produces a [`Command`] that queries the visible screen bounds of the
+/// [`Button`] with the given [`Id`].
+///
+/// This is useful to anchor a popup right next to a [`Button`].
+pub fn visible_bounds(id: Id) -> Command<Option<Rectangle>> {
+    container::visible_bounds_of(id.0)
+}
+
+/// Produces a [`Command`] that enables the [`Button`] with the given [`Id`],
+/// undoing a previous [`disable`].
+///
+/// A [`Button`] without an [`on_press`](Button::on_press) handler stays
+/// disabled.
+pub fn enable<Message: 'static>(id: Id) -> Command<Message> {
+    set_disabled(id, false)
+}
+
+/// Produces a [`Command`] that disables the [`Button`] with the given [`Id`],
+/// regardless of its [`on_press`](Button::on_press) handler.
+///
+/// The [`Button`] stays disabled until an [`enable`] command is issued, which
+/// allows toggling it between frames without recomputing its handler in
+/// `view`.
+pub fn disable<Message: 'static>(id: Id) -> Command<Message> {
+    set_disabled(id, true)
+}
+
+fn set_disabled<Message: 'static>(
+    id: Id,
+    is_disabled: bool,
+) -> Command<Message> {
+    struct SetDisabled {
+        target: widget::Id,
+        is_disabled: bool,
+    }
+
+    impl<T> Operation<T> for SetDisabled {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(
+            &mut self,
+            state: &mut dyn std::any::Any,
+            id: Option<&widget::Id>,
+        ) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<State>() {
+                state.is_disabled = self.is_disabled;
+            }
+        }
+    }
+
+    Command::widget(SetDisabled {
+        target: id.0,
+        is_disabled,
+    })
+}
+
 /// The default [`Padding`] of a [`Button`].
 pub(crate) const DEFAULT_PADDING: Padding = Padding {
     top: 5.0,
@@ -410,6 +980,8 @@ pub enum Status {
     Active,
     /// The [`Button`] can be pressed and it is being hovered.
     Hovered,
+    /// The [`Button`] can be pressed and it has been focused.
+    Focused,
     /// The [`Button`] is being pressed.
     Pressed,
     /// The [`Button`] cannot be pressed.
@@ -450,6 +1022,102 @@ impl Default for Style {
     }
 }
 
+/// Interpolates between two [`Style`] by the given factor in `0.0..=1.0`.
+///
+/// Backgrounds of different kinds, or gradients with different shapes, can't
+/// be interpolated and change at the halfway point instead.
+fn mix(a: Style, b: Style, factor: f32) -> Style {
+    fn color(a: Color, b: Color, factor: f32) -> Color {
+        Color {
+            r: a.r + (b.r - a.r) * factor,
+            g: a.g + (b.g - a.g) * factor,
+            b: a.b + (b.b - a.b) * factor,
+            a: a.a + (b.a - a.a) * factor,
+        }
+    }
+
+    fn float(a: f32, b: f32, factor: f32) -> f32 {
+        a + (b - a) * factor
+    }
+
+    fn linear(
+        a: gradient::Linear,
+        b: gradient::Linear,
+        factor: f32,
+    ) -> gradient::Linear {
+        let snapped = if factor < 0.5 { a } else { b };
+
+        if a.angle != b.angle {
+            return snapped;
+        }
+
+        let mut stops = a.stops;
+
+        for (stop, b) in stops.iter_mut().zip(b.stops) {
+            match (stop, b) {
+                (Some(a), Some(b)) if a.offset == b.offset => {
+                    a.color = color(a.color, b.color, factor);
+                }
+                (None, None) => {}
+                _ => return snapped,
+            }
+        }
+
+        gradient::Linear { stops, ..a }
+    }
+
+    let background = match (a.background, b.background) {
+        (Some(Background::Color(a)), Some(Background::Color(b))) => {
+            Some(Background::Color(color(a, b, factor)))
+        }
+        (Some(Background::Color(a)), None) => {
+            Some(Background::Color(color(a, Color::TRANSPARENT, factor)))
+        }
+        (None, Some(Background::Color(b))) => {
+            Some(Background::Color(color(Color::TRANSPARENT, b, factor)))
+        }
+        (
+            Some(Background::Gradient(Gradient::Linear(a))),
+            Some(Background::Gradient(Gradient::Linear(b))),
+        ) => Some(Background::Gradient(Gradient::Linear(linear(a, b, factor)))),
+        (a, b) => {
+            if factor < 0.5 {
+                a
+            } else {
+                b
+            }
+        }
+    };
+
+    let radius_a: [f32; 4] = a.border.radius.into();
+    let radius_b: [f32; 4] = b.border.radius.into();
+
+    Style {
+        background,
+        text_color: color(a.text_color, b.text_color, factor),
+        border: Border {
+            color: color(a.border.color, b.border.color, factor),
+            width: float(a.border.width, b.border.width, factor),
+            radius: std::array::from_fn::<f32, 4, _>(|i| {
+                float(radius_a[i], radius_b[i], factor)
+            })
+            .into(),
+        },
+        shadow: Shadow {
+            color: color(a.shadow.color, b.shadow.color, factor),
+            offset: Vector::new(
+                float(a.shadow.offset.x, b.shadow.offset.x, factor),
+                float(a.shadow.offset.y, b.shadow.offset.y, factor),
+            ),
+            blur_radius: float(
+                a.shadow.blur_radius,
+                b.shadow.blur_radius,
+                factor,
+            ),
+        },
+    }
+}
+
 /// The theme catalog of a [`Button`].
 pub trait Catalog {
     /// The item class of the [`Catalog`].
@@ -488,6 +1156,7 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.primary.base.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -503,6 +1172,7 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.secondary.strong.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -518,6 +1188,7 @@ pub fn success(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.success.strong.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -533,6 +1204,7 @@ pub fn danger(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.danger.strong.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -552,6 +1224,7 @@ pub fn text(theme: &Theme, status: Status) -> Style {
             text_color: palette.background.base.text.scale_alpha(0.8),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -565,6 +1238,17 @@ fn styled(pair: palette::Pair) -> Style {
     }
 }
 
+fn focused(style: Style) -> Style {
+    Style {
+        border: Border {
+            color: style.text_color,
+            width: 1.0,
+            ..style.border
+        },
+        ..style
+    }
+}
+
 fn disabled(style: Style) -> Style {
     Style {
         background: style
@@ -574,3 +1258,482 @@ fn disabled(style: Style) -> Style {
         ..style
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::Point;
+    use crate::Space;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Pressed;
+
+    struct Harness<Message = Pressed> {
+        element: Element<'static, Message, Theme, ()>,
+        tree: Tree,
+        node: layout::Node,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            Self::with(Button::new(Space::new(50, 20)).on_press(Pressed))
+        }
+    }
+
+    impl<Message: Clone + 'static> Harness<Message> {
+        fn with(
+            element: impl Into<Element<'static, Message, Theme, ()>>,
+        ) -> Self {
+            let element = element.into();
+            let mut tree = Tree::new(&element);
+            let node = Self::layout(&element, &mut tree);
+
+            Self {
+                element,
+                tree,
+                node,
+            }
+        }
+
+        fn layout(
+            element: &Element<'static, Message, Theme, ()>,
+            tree: &mut Tree,
+        ) -> layout::Node {
+            element.as_widget().layout(
+                tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+            )
+        }
+
+        fn rebuild(
+            &mut self,
+            element: impl Into<Element<'static, Message, Theme, ()>>,
+        ) {
+            self.element = element.into();
+            self.tree.diff(&self.element);
+            self.node = Self::layout(&self.element, &mut self.tree);
+        }
+
+        fn focus(&mut self, id: &'static str) {
+            self.operate(operation::focusable::focus(widget::Id::new(id)));
+        }
+
+        fn operate(&mut self, operation: impl Operation<Message> + 'static) {
+            let mut operation: Box<dyn Operation<Message>> =
+                Box::new(operation);
+
+            loop {
+                self.element.as_widget().operate(
+                    &mut self.tree,
+                    Layout::new(&self.node),
+                    &(),
+                    operation.as_mut(),
+                );
+
+                match operation.finish() {
+                    operation::Outcome::Chain(next) => {
+                        operation = next;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        fn update(&mut self, event: Event, cursor: Point) -> Vec<Message> {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = self.element.as_widget_mut().on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&self.node),
+                mouse::Cursor::Available(cursor),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::new(Point::ORIGIN, Size::new(200.0, 200.0)),
+            );
+
+            messages
+        }
+
+        fn focus_root(&mut self) {
+            operation::Focusable::focus(
+                self.tree.state.downcast_mut::<State>(),
+            );
+        }
+
+        fn set_disabled(&mut self, is_disabled: bool) {
+            self.tree.state.downcast_mut::<State>().is_disabled = is_disabled;
+        }
+
+        fn is_pressed(&self) -> bool {
+            self.tree.state.downcast_ref::<State>().is_pressed
+        }
+    }
+
+    fn layout(button: Button<'static, Pressed, Theme, ()>) -> layout::Node {
+        let button: Element<'static, Pressed, Theme, ()> = button.into();
+        let mut tree = Tree::new(&button);
+
+        button.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        )
+    }
+
+    const INSIDE: Point = Point::new(10.0, 10.0);
+    const OUTSIDE: Point = Point::new(150.0, 150.0);
+
+    fn pressed(button: mouse::Button) -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(button))
+    }
+
+    fn released(button: mouse::Button) -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(button))
+    }
+
+    fn key_pressed(
+        key: keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers,
+            text: None,
+        })
+    }
+
+    fn enter() -> keyboard::Key {
+        keyboard::Key::Named(key::Named::Enter)
+    }
+
+    #[test]
+    fn fixed_width_centers_content() {
+        let node = layout(
+            Button::new(Space::new(20, 10))
+                .width(100)
+                .align_x(alignment::Horizontal::Center),
+        );
+
+        assert_eq!(node.size().width, 100.0);
+        assert_eq!(
+            node.children()[0].bounds(),
+            Rectangle::new(Point::new(40.0, 5.0), Size::new(20.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn min_width_centers_content() {
+        let node = layout(
+            Button::new(Space::new(20, 10))
+                .min_width(120)
+                .align_x(alignment::Horizontal::Center),
+        );
+
+        assert_eq!(node.size().width, 120.0);
+        assert_eq!(node.children()[0].bounds().x, 50.0);
+        assert_eq!(node.children()[0].bounds().width, 20.0);
+    }
+
+    #[test]
+    fn disabled_content_widens_shrinking_button() {
+        let node = layout(
+            Button::new(Space::new(20, 10))
+                .disabled_content(Space::new(60, 10))
+                .on_press(Pressed),
+        );
+
+        assert_eq!(node.size().width, 80.0);
+    }
+
+    #[test]
+    fn disabled_content_keeps_fixed_width() {
+        let node = layout(
+            Button::new(Space::new(20, 10))
+                .disabled_content(Space::new(150, 10))
+                .width(100),
+        );
+
+        assert_eq!(node.size().width, 100.0);
+    }
+
+    #[test]
+    fn diff_with_unchanged_label_keeps_children() {
+        let counter = |items: usize| -> Element<'static, Pressed, Theme, ()> {
+            Button::new(crate::text(format!("{items} items")))
+                .on_press(Pressed)
+                .into()
+        };
+
+        let mut tree = Tree::new(&counter(3));
+
+        // Replace the state of the label with a marker that would be lost if
+        // the child tree was rebuilt.
+        tree.children[0].state = tree::State::new(3_usize);
+
+        counter(3).as_widget().diff(&mut tree);
+
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].state.downcast_ref::<usize>(), &3);
+    }
+
+    #[test]
+    fn release_inside_publishes() {
+        let mut harness = Harness::new();
+
+        assert!(harness
+            .update(pressed(mouse::Button::Left), INSIDE)
+            .is_empty());
+        assert!(harness.is_pressed());
+
+        assert_eq!(
+            harness.update(released(mouse::Button::Left), INSIDE),
+            [Pressed]
+        );
+        assert!(!harness.is_pressed());
+    }
+
+    #[test]
+    fn click_does_not_focus() {
+        let mut harness = Harness::new();
+
+        let _ = harness.update(pressed(mouse::Button::Left), INSIDE);
+        let _ = harness.update(released(mouse::Button::Left), INSIDE);
+
+        assert!(!harness.tree.state.downcast_ref::<State>().is_focused);
+        assert!(harness
+            .update(
+                key_pressed(
+                    keyboard::Key::Named(key::Named::Space),
+                    keyboard::Modifiers::empty(),
+                ),
+                OUTSIDE,
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn enter_with_command_modifiers_does_not_activate() {
+        let mut harness = Harness::new();
+        harness.focus_root();
+
+        assert!(harness
+            .update(key_pressed(enter(), keyboard::Modifiers::COMMAND), INSIDE)
+            .is_empty());
+        assert!(harness
+            .update(key_pressed(enter(), keyboard::Modifiers::ALT), INSIDE)
+            .is_empty());
+        assert_eq!(
+            harness.update(
+                key_pressed(enter(), keyboard::Modifiers::SHIFT),
+                INSIDE
+            ),
+            [Pressed]
+        );
+    }
+
+    #[test]
+    fn on_press_key_matches_exact_modifiers() {
+        let key = keyboard::Key::Character("s".into());
+
+        let mut harness = Harness::with(
+            Button::new(Space::new(50, 20))
+                .on_press(Pressed)
+                .on_press_key_with_modifiers(
+                    key.clone(),
+                    keyboard::Modifiers::CTRL,
+                ),
+        );
+        harness.focus_root();
+
+        assert_eq!(
+            harness.update(
+                key_pressed(key.clone(), keyboard::Modifiers::CTRL),
+                INSIDE
+            ),
+            [Pressed]
+        );
+        assert!(harness
+            .update(
+                key_pressed(key.clone(), keyboard::Modifiers::empty()),
+                INSIDE
+            )
+            .is_empty());
+        assert!(harness
+            .update(
+                key_pressed(
+                    key,
+                    keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT
+                ),
+                INSIDE
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn reading_order_follows_centered_row() {
+        let button = |height: u16, message: char| {
+            Button::new(Space::new(20, height)).on_press(message)
+        };
+
+        let mut harness = Harness::with(
+            crate::Row::new()
+                .align_items(Alignment::Center)
+                .push(button(10, 'a'))
+                .push(button(30, 'b'))
+                .push(button(20, 'c')),
+        );
+
+        let focus_order: Vec<_> = (0..3)
+            .flat_map(|_| {
+                harness.operate(
+                    operation::focusable::focus_next_in_reading_order(),
+                );
+
+                harness.update(
+                    key_pressed(enter(), keyboard::Modifiers::empty()),
+                    OUTSIDE,
+                )
+            })
+            .collect();
+
+        assert_eq!(focus_order, ['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn disabled_button_loses_focus() {
+        let toolbar = |is_first_enabled: bool| {
+            crate::Column::new()
+                .push(
+                    Button::new(Space::new(20, 10))
+                        .id(Id::new("first"))
+                        .on_press_maybe(is_first_enabled.then_some('a')),
+                )
+                .push(
+                    Button::new(Space::new(20, 10))
+                        .id(Id::new("second"))
+                        .on_press('b'),
+                )
+        };
+
+        let mut harness = Harness::with(toolbar(true));
+        harness.focus("first");
+
+        harness.rebuild(toolbar(false));
+        harness.focus("second");
+
+        harness.rebuild(toolbar(true));
+
+        assert_eq!(
+            harness.update(
+                key_pressed(enter(), keyboard::Modifiers::empty()),
+                OUTSIDE
+            ),
+            ['b']
+        );
+    }
+
+    #[test]
+    fn release_outside_clears_pressed() {
+        let mut harness = Harness::new();
+
+        let _ = harness.update(pressed(mouse::Button::Left), INSIDE);
+
+        assert!(harness
+            .update(released(mouse::Button::Left), OUTSIDE)
+            .is_empty());
+        assert!(!harness.is_pressed());
+    }
+
+    #[test]
+    fn release_outside_after_other_button_clears_pressed() {
+        let mut harness = Harness::new();
+
+        let _ = harness.update(pressed(mouse::Button::Left), INSIDE);
+        let _ = harness.update(pressed(mouse::Button::Right), INSIDE);
+
+        assert!(harness
+            .update(released(mouse::Button::Left), OUTSIDE)
+            .is_empty());
+        assert!(!harness.is_pressed());
+
+        assert!(harness
+            .update(released(mouse::Button::Right), INSIDE)
+            .is_empty());
+    }
+
+    #[test]
+    fn release_while_disabled_clears_pressed() {
+        let mut harness = Harness::new();
+
+        let _ = harness.update(pressed(mouse::Button::Left), INSIDE);
+        harness.set_disabled(true);
+
+        assert!(harness
+            .update(released(mouse::Button::Left), INSIDE)
+            .is_empty());
+        assert!(!harness.is_pressed());
+
+        harness.set_disabled(false);
+
+        assert!(harness
+            .update(released(mouse::Button::Left), INSIDE)
+            .is_empty());
+    }
+
+    #[test]
+    fn release_of_other_button_does_not_publish() {
+        let mut harness = Harness::with(
+            Button::new(Space::new(50, 20))
+                .on_press(Pressed)
+                .mouse_buttons(&[mouse::Button::Left, mouse::Button::Right]),
+        );
+
+        let _ = harness.update(pressed(mouse::Button::Left), INSIDE);
+        let _ = harness.update(pressed(mouse::Button::Right), INSIDE);
+
+        assert!(harness
+            .update(released(mouse::Button::Right), INSIDE)
+            .is_empty());
+        assert!(harness.is_pressed());
+
+        assert_eq!(
+            harness.update(released(mouse::Button::Left), INSIDE),
+            [Pressed]
+        );
+    }
+
+    #[test]
+    fn cursor_leaving_clears_pressed() {
+        let mut harness = Harness::new();
+
+        let _ = harness.update(pressed(mouse::Button::Left), INSIDE);
+        let _ = harness.update(Event::Mouse(mouse::Event::CursorLeft), OUTSIDE);
+
+        assert!(!harness.is_pressed());
+        assert!(harness
+            .update(released(mouse::Button::Left), INSIDE)
+            .is_empty());
+    }
+
+    #[test]
+    fn window_unfocus_clears_pressed() {
+        let mut harness = Harness::new();
+
+        let _ = harness.update(pressed(mouse::Button::Left), INSIDE);
+        let _ = harness.update(
+            Event::Window(window::Id::MAIN, window::Event::Unfocused),
+            INSIDE,
+        );
+
+        assert!(!harness.is_pressed());
+        assert!(harness
+            .update(released(mouse::Button::Left), INSIDE)
+            .is_empty());
+    }
+}
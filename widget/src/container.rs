@@ -409,6 +409,14 @@ impl From<Id> for widget::Id {
 /// Produces a [`Command`] that queries the visible screen bounds of the
 /// [`Container`] with the given [`Id`].
 pub fn visible_bounds(id: Id) -> Command<Option<Rectangle>> {
+    visible_bounds_of(id.into())
+}
+
+/// Produces a [`Command`] that queries the visible screen bounds of the
+/// widget reporting the given [`widget::Id`] as a container.
+pub(crate) fn visible_bounds_of(
+    target: widget::Id,
+) -> Command<Option<Rectangle>> {
     struct VisibleBounds {
         target: widget::Id,
         depth: usize,
@@ -488,7 +496,7 @@ pub fn visible_bounds(id: Id) -> Command<Option<Rectangle>> {
     }
 
     Command::widget(VisibleBounds {
-        target: id.into(),
+        target,
         depth: 0,
         scrollables: Vec::new(),
         bounds: None,
@@ -428,6 +428,46 @@ where
     Command::widget(operation::focusable::focus_next())
 }
 
+/// Focuses the previous focusable widget, wrapping around to the last one
+/// when the first one is focused.
+pub fn focus_previous_wrapping<Message>() -> Command<Message>
+where
+    Message: 'static,
+{
+    Command::widget(operation::focusable::focus_previous_wrapping())
+}
+
+/// Focuses the next focusable widget, wrapping around to the first one when
+/// the last one is focused.
+pub fn focus_next_wrapping<Message>() -> Command<Message>
+where
+    Message: 'static,
+{
+    Command::widget(operation::focusable::focus_next_wrapping())
+}
+
+/// Focuses the previous focusable widget in reading order.
+///
+/// Unlike [`focus_previous`], this follows the visual position of the
+/// focusable widgets instead of their order in the widget tree.
+pub fn focus_previous_in_reading_order<Message>() -> Command<Message>
+where
+    Message: 'static,
+{
+    Command::widget(operation::focusable::focus_previous_in_reading_order())
+}
+
+/// Focuses the next focusable widget in reading order.
+///
+/// Unlike [`focus_next`], this follows the visual position of the
+/// focusable widgets instead of their order in the widget tree.
+pub fn focus_next_in_reading_order<Message>() -> Command<Message>
+where
+    Message: 'static,
+{
+    Command::widget(operation::focusable::focus_next_in_reading_order())
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -325,6 +325,7 @@ where
             }
             Event::Mouse(mouse::Event::WheelScrolled {
                 delta: mouse::ScrollDelta::Lines { y, .. },
+                ..
             }) => {
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
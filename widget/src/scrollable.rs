@@ -414,7 +414,7 @@ where
         }
 
         match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+            Event::Mouse(mouse::Event::WheelScrolled { delta, .. }) => {
                 if cursor_over_scrollable.is_none() {
                     return event::Status::Ignored;
                 }
@@ -677,7 +677,7 @@ impl Update {
                     }
                     _ => None,
                 },
-                mouse::Event::WheelScrolled { delta }
+                mouse::Event::WheelScrolled { delta, .. }
                     if cursor.is_over(bounds) =>
                 {
                     Some(Update::Scroll(match delta {
@@ -535,13 +535,17 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        operation.focusable(
+            state,
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+        );
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
     }
 
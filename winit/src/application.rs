@@ -23,6 +23,7 @@ use crate::{Clipboard, Error, Proxy, Settings};
 
 use futures::channel::mpsc;
 
+use std::collections::HashSet;
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
 
@@ -169,6 +170,8 @@ where
 
     let should_be_visible = settings.window.visible;
     let exit_on_close_request = settings.window.exit_on_close_request;
+    let key_repeat = settings.key_repeat;
+    let touch_as_mouse = settings.touch_as_mouse;
 
     let builder = conversion::window_settings(
         settings.window,
@@ -245,6 +248,7 @@ where
         window,
         should_be_visible,
         exit_on_close_request,
+        touch_as_mouse,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -255,6 +259,10 @@ where
                 return;
             }
 
+            if !key_repeat && conversion::is_key_repeat(&event) {
+                return;
+            }
+
             event_sender.start_send(event).expect("Send event");
 
             let poll = instance.as_mut().poll(&mut context);
@@ -317,6 +325,7 @@ async fn run_instance<A, E, C>(
     window: Arc<winit::window::Window>,
     should_be_visible: bool,
     exit_on_close_request: bool,
+    touch_as_mouse: bool,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -349,7 +358,7 @@ async fn run_instance<A, E, C>(
         &mut compositor,
         &mut surface,
         &mut cache,
-        &state,
+        &mut state,
         &mut renderer,
         init_command,
         &mut runtime,
@@ -372,6 +381,8 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut repeating_keys = HashSet::new();
+    let mut primary_finger = None;
     let mut redraw_pending = false;
 
     debug.startup_finished();
@@ -524,16 +535,46 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
+                let previous_scale_factor = state.scale_factor();
+
                 state.update(&window, &window_event, &mut debug);
 
+                if matches!(
+                    window_event,
+                    winit::event::WindowEvent::ScaleFactorChanged { .. }
+                ) {
+                    events.push(Event::Window(
+                        window::Id::MAIN,
+                        window::Event::Rescaled {
+                            previous_scale_factor,
+                            scale_factor: state.scale_factor(),
+                        },
+                    ));
+                }
+
+                let platform_specific_events =
+                    conversion::platform_specific_events(
+                        &window_event,
+                        &mut repeating_keys,
+                    );
+
                 if let Some(event) = conversion::window_event(
                     window::Id::MAIN,
                     window_event,
                     state.scale_factor(),
                     state.modifiers(),
                 ) {
+                    let mouse_events = if touch_as_mouse {
+                        conversion::touch_to_mouse(&event, &mut primary_finger)
+                    } else {
+                        Vec::new()
+                    };
+
                     events.push(event);
+                    events.extend(mouse_events);
                 }
+
+                events.extend(platform_specific_events);
             }
             event::Event::AboutToWait => {
                 if events.is_empty() && messages.is_empty() {
@@ -713,7 +754,7 @@ pub fn run_command<A, C, E>(
     compositor: &mut C,
     surface: &mut C::Surface,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<A>,
     renderer: &mut A::Renderer,
     command: Command<A::Message>,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
@@ -751,6 +792,13 @@ pub fn run_command<A, C, E>(
                 clipboard::Action::Write(contents, kind) => {
                     clipboard.write(kind, contents);
                 }
+                clipboard::Action::IsOwner(tag, kind) => {
+                    let message = tag(clipboard.is_owner(kind));
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
             },
             command::Action::Window(action) => match action {
                 window::Action::Close(_id) => {
@@ -772,6 +820,9 @@ pub fn run_command<A, C, E>(
                             height: size.height,
                         });
                 }
+                window::Action::ChangeAspectRatio(_id, aspect_ratio) => {
+                    state.set_aspect_ratio(window, aspect_ratio);
+                }
                 window::Action::FetchSize(_id, callback) => {
                     let size =
                         window.inner_size().to_logical(window.scale_factor());
@@ -820,6 +871,18 @@ pub fn run_command<A, C, E>(
                         y: position.y,
                     });
                 }
+                window::Action::SetImeCursorArea(_id, area) => {
+                    window.set_ime_cursor_area(
+                        winit::dpi::LogicalPosition {
+                            x: area.x,
+                            y: area.y,
+                        },
+                        winit::dpi::LogicalSize {
+                            width: area.width,
+                            height: area.height,
+                        },
+                    );
+                }
                 window::Action::ChangeMode(_id, mode) => {
                     window.set_visible(conversion::visible(mode));
                     window.set_fullscreen(conversion::fullscreen(
@@ -841,6 +904,11 @@ pub fn run_command<A, C, E>(
                         .send_event(tag(mode))
                         .expect("Send message to event loop");
                 }
+                window::Action::FetchPressedKeys(_id, tag) => {
+                    proxy
+                        .send_event(tag(state.pressed_keys()))
+                        .expect("Send message to event loop");
+                }
                 window::Action::ToggleMaximize(_id) => {
                     window.set_maximized(!window.is_maximized());
                 }
@@ -866,11 +934,22 @@ pub fn run_command<A, C, E>(
                         });
                     }
                 }
+                window::Action::ShowSystemMenuAt(_id, position) => {
+                    window.show_window_menu(winit::dpi::LogicalPosition {
+                        x: position.x,
+                        y: position.y,
+                    });
+                }
                 window::Action::FetchId(_id, tag) => {
                     proxy
                         .send_event(tag(window.id().into()))
                         .expect("Send message to event loop");
                 }
+                window::Action::FetchAll(tag) => {
+                    proxy
+                        .send_event(tag(vec![core::window::Id::MAIN]))
+                        .expect("Send message to event loop");
+                }
                 window::Action::RunWithHandle(_id, tag) => {
                     use window::raw_window_handle::HasWindowHandle;
 
@@ -881,6 +960,12 @@ pub fn run_command<A, C, E>(
                     }
                 }
 
+                window::Action::EnableMousePassthrough(_id) => {
+                    let _ = window.set_cursor_hittest(false);
+                }
+                window::Action::DisableMousePassthrough(_id) => {
+                    let _ = window.set_cursor_hittest(true);
+                }
                 window::Action::Screenshot(_id, tag) => {
                     let bytes = compositor.screenshot(
                         renderer,
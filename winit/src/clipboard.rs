@@ -7,6 +7,7 @@ use crate::core::clipboard::Kind;
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
+    owned: Owned,
 }
 
 enum State {
@@ -14,6 +15,29 @@ enum State {
     Unavailable,
 }
 
+/// The contents most recently written by this application, per [`Kind`].
+#[derive(Default)]
+struct Owned {
+    standard: Option<String>,
+    primary: Option<String>,
+}
+
+impl Owned {
+    fn get(&self, kind: Kind) -> Option<&String> {
+        match kind {
+            Kind::Standard => self.standard.as_ref(),
+            Kind::Primary => self.primary.as_ref(),
+        }
+    }
+
+    fn set(&mut self, kind: Kind, contents: Option<String>) {
+        match kind {
+            Kind::Standard => self.standard = contents,
+            Kind::Primary => self.primary = contents,
+        }
+    }
+}
+
 impl Clipboard {
     /// Creates a new [`Clipboard`] for the given window.
     pub fn connect(window: &winit::window::Window) -> Clipboard {
@@ -23,7 +47,10 @@ impl Clipboard {
             .map(State::Connected)
             .unwrap_or(State::Unavailable);
 
-        Clipboard { state }
+        Clipboard {
+            state,
+            owned: Owned::default(),
+        }
     }
 
     /// Creates a new [`Clipboard`] that isn't associated with a window.
@@ -31,6 +58,7 @@ impl Clipboard {
     pub fn unconnected() -> Clipboard {
         Clipboard {
             state: State::Unavailable,
+            owned: Owned::default(),
         }
     }
 
@@ -50,15 +78,19 @@ impl Clipboard {
         match &mut self.state {
             State::Connected(clipboard) => {
                 let result = match kind {
-                    Kind::Standard => clipboard.write(contents),
-                    Kind::Primary => {
-                        clipboard.write_primary(contents).unwrap_or(Ok(()))
-                    }
+                    Kind::Standard => clipboard.write(contents.clone()),
+                    Kind::Primary => clipboard
+                        .write_primary(contents.clone())
+                        .unwrap_or(Ok(())),
                 };
 
                 match result {
-                    Ok(()) => {}
+                    Ok(()) => {
+                        self.owned.set(kind, Some(contents));
+                    }
                     Err(error) => {
+                        self.owned.set(kind, None);
+
                         log::warn!("error writing to clipboard: {error}");
                     }
                 }
@@ -66,6 +98,23 @@ impl Clipboard {
             State::Unavailable => {}
         }
     }
+
+    /// Returns whether this application currently owns the [`Clipboard`] of
+    /// the given [`Kind`].
+    ///
+    /// The [`Clipboard`] is owned if the last contents written by this
+    /// application are still the current contents, even if another
+    /// application has written the same contents since. It is never owned
+    /// while unavailable.
+    pub fn is_owner(&self, kind: Kind) -> bool {
+        match &self.state {
+            State::Connected(_) => self
+                .owned
+                .get(kind)
+                .is_some_and(|owned| self.read(kind).as_ref() == Some(owned)),
+            State::Unavailable => false,
+        }
+    }
 }
 
 impl crate::core::Clipboard for Clipboard {
@@ -8,6 +8,8 @@ use crate::core::touch;
 use crate::core::window;
 use crate::core::{Event, Point, Size};
 
+use std::collections::HashSet;
+
 /// Converts some [`window::Settings`] into a `WindowBuilder` from `winit`.
 pub fn window_settings(
     settings: window::Settings,
@@ -177,24 +179,28 @@ pub fn window_event(
                 }
             }))
         }
-        WindowEvent::MouseWheel { delta, .. } => match delta {
-            winit::event::MouseScrollDelta::LineDelta(delta_x, delta_y) => {
-                Some(Event::Mouse(mouse::Event::WheelScrolled {
-                    delta: mouse::ScrollDelta::Lines {
+        WindowEvent::MouseWheel { delta, .. } => {
+            let source = scroll_source(delta);
+
+            Some(Event::Mouse(mouse::Event::WheelScrolled {
+                delta: match delta {
+                    winit::event::MouseScrollDelta::LineDelta(
+                        delta_x,
+                        delta_y,
+                    ) => mouse::ScrollDelta::Lines {
                         x: delta_x,
                         y: delta_y,
                     },
-                }))
-            }
-            winit::event::MouseScrollDelta::PixelDelta(position) => {
-                Some(Event::Mouse(mouse::Event::WheelScrolled {
-                    delta: mouse::ScrollDelta::Pixels {
-                        x: position.x as f32,
-                        y: position.y as f32,
-                    },
-                }))
-            }
-        },
+                    winit::event::MouseScrollDelta::PixelDelta(position) => {
+                        mouse::ScrollDelta::Pixels {
+                            x: position.x as f32,
+                            y: position.y as f32,
+                        }
+                    }
+                },
+                source,
+            }))
+        }
         WindowEvent::KeyboardInput { event, .. } => Some(Event::Keyboard({
             let logical_key = {
                 #[cfg(not(target_arch = "wasm32"))]
@@ -276,6 +282,9 @@ pub fn window_event(
                 window::Event::Unfocused
             },
         )),
+        WindowEvent::Occluded(occluded) => {
+            Some(Event::Window(id, window::Event::Occluded(occluded)))
+        }
         WindowEvent::HoveredFile(path) => {
             Some(Event::Window(id, window::Event::FileHovered(path.clone())))
         }
@@ -298,6 +307,133 @@ pub fn window_event(
     }
 }
 
+/// Converts a winit window event into platform specific iced events.
+///
+/// These events are meant to be produced alongside the one returned by
+/// [`window_event`].
+///
+/// The scancodes of the keys that are currently repeating are tracked in
+/// `repeating_keys`, in order to notify when a key starts and stops
+/// repeating.
+pub fn platform_specific_events(
+    event: &winit::event::WindowEvent,
+    repeating_keys: &mut HashSet<u32>,
+) -> Vec<Event> {
+    use winit::event::WindowEvent;
+
+    match event {
+        #[cfg(any(
+            target_os = "windows",
+            target_os = "macos",
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
+        WindowEvent::KeyboardInput { event, .. } => {
+            use crate::core::event;
+            use crate::core::SmolStr;
+            use winit::platform::modifier_supplement::KeyEventExtModifierSupplement;
+            use winit::platform::scancode::PhysicalKeyExtScancode;
+
+            let Some(scancode) = event.physical_key.to_scancode() else {
+                return Vec::new();
+            };
+
+            let text = match event.key_without_modifiers() {
+                winit::keyboard::Key::Character(c) => Some(SmolStr::new(c)),
+                _ => None,
+            }
+            .filter(|text| !text.as_str().chars().any(is_private_use));
+
+            let mut events = Vec::new();
+
+            match event.state {
+                winit::event::ElementState::Pressed => {
+                    if event.repeat && repeating_keys.insert(scancode) {
+                        events
+                            .push(event::Keyboard::RepeatStarted { scancode });
+                    }
+
+                    events.push(event::Keyboard::KeyPressed { scancode, text });
+                }
+                winit::event::ElementState::Released => {
+                    if repeating_keys.remove(&scancode) {
+                        events.push(event::Keyboard::RepeatEnded { scancode });
+                    }
+
+                    events
+                        .push(event::Keyboard::KeyReleased { scancode, text });
+                }
+            }
+
+            events
+                .into_iter()
+                .map(|event| {
+                    Event::PlatformSpecific(event::PlatformSpecific::Keyboard(
+                        event,
+                    ))
+                })
+                .collect()
+        }
+        WindowEvent::Focused(false) => {
+            repeating_keys.clear();
+
+            Vec::new()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Updates the `pressed_keys` of a window, in the order they were pressed,
+/// with the given [`winit`] window event.
+///
+/// All the keys are released when the window loses focus, since their
+/// releases will not be received.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn update_pressed_keys(
+    pressed_keys: &mut Vec<u32>,
+    event: &winit::event::WindowEvent,
+) {
+    use winit::event::WindowEvent;
+
+    match event {
+        #[cfg(any(
+            target_os = "windows",
+            target_os = "macos",
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
+        WindowEvent::KeyboardInput { event, .. } => {
+            use winit::platform::scancode::PhysicalKeyExtScancode;
+
+            let Some(scancode) = event.physical_key.to_scancode() else {
+                return;
+            };
+
+            match event.state {
+                winit::event::ElementState::Pressed => {
+                    if !pressed_keys.contains(&scancode) {
+                        pressed_keys.push(scancode);
+                    }
+                }
+                winit::event::ElementState::Released => {
+                    pressed_keys.retain(|pressed| *pressed != scancode);
+                }
+            }
+        }
+        WindowEvent::Focused(false) => {
+            pressed_keys.clear();
+        }
+        _ => {}
+    }
+}
+
 /// Converts a [`window::Level`] to a [`winit`] window level.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -387,6 +523,30 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     }
 }
 
+/// Adjusts the `new` physical size of a window to keep the given
+/// `aspect_ratio` (width divided by height), changing the dimension that was
+/// resized the least compared to the `previous` size.
+pub fn constrain_to_aspect_ratio(
+    previous: Size<u32>,
+    new: winit::dpi::PhysicalSize<u32>,
+    aspect_ratio: f32,
+) -> winit::dpi::PhysicalSize<u32> {
+    let width_delta = new.width.abs_diff(previous.width);
+    let height_delta = new.height.abs_diff(previous.height);
+
+    if width_delta >= height_delta {
+        winit::dpi::PhysicalSize {
+            width: new.width,
+            height: (new.width as f32 / aspect_ratio).round() as u32,
+        }
+    } else {
+        winit::dpi::PhysicalSize {
+            width: (new.height as f32 * aspect_ratio).round() as u32,
+            height: new.height,
+        }
+    }
+}
+
 /// Converts a [`mouse::Interaction`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -412,6 +572,69 @@ pub fn mouse_interaction(
     }
 }
 
+/// Emulates the mouse events produced by the primary touch point of the given
+/// [`Event`], if any.
+///
+/// The primary touch point is the first finger pressed while no other finger
+/// is. It presses and releases the left mouse button.
+pub fn touch_to_mouse(
+    event: &Event,
+    primary_finger: &mut Option<touch::Finger>,
+) -> Vec<Event> {
+    let Event::Touch(event) = *event else {
+        return Vec::new();
+    };
+
+    match event {
+        touch::Event::FingerPressed { id, position }
+            if primary_finger.is_none() =>
+        {
+            *primary_finger = Some(id);
+
+            vec![
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ]
+        }
+        touch::Event::FingerMoved { id, position }
+            if *primary_finger == Some(id) =>
+        {
+            vec![Event::Mouse(mouse::Event::CursorMoved { position })]
+        }
+        touch::Event::FingerLifted { id, .. }
+            if *primary_finger == Some(id) =>
+        {
+            *primary_finger = None;
+
+            vec![Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left,
+            ))]
+        }
+        touch::Event::FingerLost { id, .. } if *primary_finger == Some(id) => {
+            *primary_finger = None;
+
+            vec![Event::Mouse(mouse::Event::CursorLeft)]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Returns whether the given [`winit`] event is a repeated key press.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn is_key_repeat<T>(event: &winit::event::Event<T>) -> bool {
+    matches!(
+        event,
+        winit::event::Event::WindowEvent {
+            event: winit::event::WindowEvent::KeyboardInput {
+                event: winit::event::KeyEvent { repeat: true, .. },
+                ..
+            },
+            ..
+        }
+    )
+}
+
 /// Converts a `MouseButton` from [`winit`] to an [`iced`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -427,6 +650,31 @@ pub fn mouse_button(mouse_button: winit::event::MouseButton) -> mouse::Button {
     }
 }
 
+/// Converts a `MouseScrollDelta` from [`winit`] to the [`iced`] scroll source
+/// that most likely produced it.
+///
+/// [`winit`] does not report the source of a scroll movement, so the result
+/// is only a heuristic: line-based deltas are assumed to come from mouse
+/// wheels, and pixel-based deltas from touchpads. High-resolution mouse wheels
+/// that report pixel-based deltas are therefore tagged as
+/// [`mouse::ScrollSource::Finger`], and [`mouse::ScrollSource::Continuous`] is
+/// never produced.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.12
+pub fn scroll_source(
+    delta: winit::event::MouseScrollDelta,
+) -> mouse::ScrollSource {
+    match delta {
+        winit::event::MouseScrollDelta::LineDelta(..) => {
+            mouse::ScrollSource::Wheel
+        }
+        winit::event::MouseScrollDelta::PixelDelta(_) => {
+            mouse::ScrollSource::Finger
+        }
+    }
+}
+
 /// Converts some `ModifiersState` from [`winit`] to an [`iced`] modifiers
 /// state.
 ///
@@ -495,6 +743,20 @@ pub fn key(key: winit::keyboard::Key) -> keyboard::Key {
     use winit::keyboard::NamedKey;
 
     match key {
+        winit::keyboard::Key::Character(c)
+            if c.chars().any(char::is_control) =>
+        {
+            // Some layouts produce control characters instead of named keys,
+            // which text widgets would insert literally otherwise.
+            match c.as_str() {
+                "\u{8}" => keyboard::Key::Named(Named::Backspace),
+                "\t" => keyboard::Key::Named(Named::Tab),
+                "\n" | "\r" => keyboard::Key::Named(Named::Enter),
+                "\u{1b}" => keyboard::Key::Named(Named::Escape),
+                "\u{7f}" => keyboard::Key::Named(Named::Delete),
+                _ => keyboard::Key::Unidentified,
+            }
+        }
         winit::keyboard::Key::Character(c) => keyboard::Key::Character(c),
         winit::keyboard::Key::Named(named_key) => {
             keyboard::Key::Named(match named_key {
@@ -844,3 +1106,107 @@ pub fn icon(icon: window::Icon) -> Option<winit::window::Icon> {
 fn is_private_use(c: char) -> bool {
     ('\u{E000}'..='\u{F8FF}').contains(&c)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scroll_source_of_line_delta_is_wheel() {
+        let delta = winit::event::MouseScrollDelta::LineDelta(0.0, 1.0);
+
+        assert_eq!(scroll_source(delta), mouse::ScrollSource::Wheel);
+    }
+
+    #[test]
+    fn scroll_source_of_pixel_delta_is_finger() {
+        let delta = winit::event::MouseScrollDelta::PixelDelta(
+            winit::dpi::PhysicalPosition::new(0.0, 12.5),
+        );
+
+        assert_eq!(scroll_source(delta), mouse::ScrollSource::Finger);
+    }
+
+    #[test]
+    fn constrain_to_aspect_ratio_follows_the_largest_change() {
+        let previous = Size::new(800, 600);
+
+        assert_eq!(
+            constrain_to_aspect_ratio(
+                previous,
+                winit::dpi::PhysicalSize::new(1000, 610),
+                4.0 / 3.0,
+            ),
+            winit::dpi::PhysicalSize::new(1000, 750)
+        );
+
+        assert_eq!(
+            constrain_to_aspect_ratio(
+                previous,
+                winit::dpi::PhysicalSize::new(790, 300),
+                4.0 / 3.0,
+            ),
+            winit::dpi::PhysicalSize::new(400, 300)
+        );
+    }
+
+    #[test]
+    fn constrain_to_aspect_ratio_keeps_matching_sizes() {
+        let size = winit::dpi::PhysicalSize::new(1920, 1080);
+
+        assert_eq!(
+            constrain_to_aspect_ratio(Size::new(1280, 720), size, 16.0 / 9.0),
+            size
+        );
+    }
+
+    #[test]
+    fn touch_to_mouse_follows_primary_finger() {
+        let position = Point::new(10.0, 20.0);
+        let mut primary_finger = None;
+
+        let mut touch =
+            |event| touch_to_mouse(&Event::Touch(event), &mut primary_finger);
+
+        assert_eq!(
+            touch(touch::Event::FingerPressed {
+                id: touch::Finger(0),
+                position,
+            }),
+            [
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ]
+        );
+
+        assert!(touch(touch::Event::FingerPressed {
+            id: touch::Finger(1),
+            position,
+        })
+        .is_empty());
+
+        assert_eq!(
+            touch(touch::Event::FingerLifted {
+                id: touch::Finger(0),
+                position,
+            }),
+            [Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left
+            ))]
+        );
+    }
+
+    #[test]
+    fn control_characters_are_not_character_keys() {
+        use keyboard::key::Named;
+
+        let character = |c| winit::keyboard::Key::Character(c.into());
+
+        assert_eq!(
+            key(character("\u{1b}")),
+            keyboard::Key::Named(Named::Escape)
+        );
+        assert_eq!(key(character("\u{1}")), keyboard::Key::Unidentified);
+        assert_eq!(key(character("a")), keyboard::Key::Character("a".into()));
+    }
+}
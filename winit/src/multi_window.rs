@@ -27,7 +27,7 @@ use crate::{Clipboard, Error, Proxy, Settings};
 
 pub use crate::application::{default, Appearance, DefaultStyle};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
 use std::time::Instant;
@@ -140,6 +140,8 @@ where
 
     let should_main_be_visible = settings.window.visible;
     let exit_on_close_request = settings.window.exit_on_close_request;
+    let key_repeat = settings.key_repeat;
+    let touch_as_mouse = settings.touch_as_mouse;
 
     let builder = conversion::window_settings(
         settings.window,
@@ -213,6 +215,7 @@ where
         init_command,
         window_manager,
         should_main_be_visible,
+        touch_as_mouse,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -222,6 +225,10 @@ where
             return;
         }
 
+        if !key_repeat && conversion::is_key_repeat(&event) {
+            return;
+        }
+
         event_sender
             .start_send(Event::EventLoopAwakened(event))
             .expect("Send event");
@@ -258,19 +265,23 @@ where
                             let exit_on_close_request =
                                 settings.exit_on_close_request;
 
-                            let window = conversion::window_settings(
+                            let event = match conversion::window_settings(
                                 settings, &title, monitor, None,
                             )
                             .build(event_loop)
-                            .expect("Failed to build window");
-
-                            event_sender
-                                .start_send(Event::WindowCreated {
+                            {
+                                Ok(window) => Event::WindowCreated {
                                     id,
                                     window,
                                     exit_on_close_request,
-                                })
-                                .expect("Send event");
+                                },
+                                Err(error) => Event::WindowCreationFailed {
+                                    id,
+                                    reason: error.to_string(),
+                                },
+                            };
+
+                            event_sender.start_send(event).expect("Send event");
                         }
                         Control::Exit => {
                             event_loop.exit();
@@ -325,6 +336,10 @@ enum Event<Message: 'static> {
         window: winit::window::Window,
         exit_on_close_request: bool,
     },
+    WindowCreationFailed {
+        id: window::Id,
+        reason: String,
+    },
     EventLoopAwakened(winit::event::Event<Message>),
 }
 
@@ -350,6 +365,7 @@ async fn run_instance<A, E, C>(
     init_command: Command<A::Message>,
     mut window_manager: WindowManager<A, C>,
     should_main_window_be_visible: bool,
+    touch_as_mouse: bool,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -408,6 +424,8 @@ async fn run_instance<A, E, C>(
     runtime.track(application.subscription().into_recipes());
 
     let mut messages = Vec::new();
+    let mut repeating_keys = HashSet::new();
+    let mut primary_finger = None;
 
     debug.startup_finished();
 
@@ -452,6 +470,14 @@ async fn run_instance<A, E, C>(
                     ),
                 ));
             }
+            Event::WindowCreationFailed { id, reason } => {
+                log::error!("Failed to create window {id:?}: {reason}");
+
+                events.push((
+                    None,
+                    core::Event::Window(id, window::Event::OpenFailed(reason)),
+                ));
+            }
             Event::EventLoopAwakened(event) => {
                 match event {
                     event::Event::NewEvents(
@@ -686,20 +712,67 @@ async fn run_instance<A, E, C>(
                                 break 'main;
                             }
                         } else {
+                            let previous_scale_factor =
+                                window.state.scale_factor();
+
                             window.state.update(
                                 &window.raw,
                                 &window_event,
                                 &mut debug,
                             );
 
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::ScaleFactorChanged { .. }
+                            ) {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(
+                                        id,
+                                        window::Event::Rescaled {
+                                            previous_scale_factor,
+                                            scale_factor: window
+                                                .state
+                                                .scale_factor(),
+                                        },
+                                    ),
+                                ));
+                            }
+
+                            let platform_specific_events =
+                                conversion::platform_specific_events(
+                                    &window_event,
+                                    &mut repeating_keys,
+                                );
+
                             if let Some(event) = conversion::window_event(
                                 id,
                                 window_event,
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
                             ) {
+                                let mouse_events = if touch_as_mouse {
+                                    conversion::touch_to_mouse(
+                                        &event,
+                                        &mut primary_finger,
+                                    )
+                                } else {
+                                    Vec::new()
+                                };
+
                                 events.push((Some(id), event));
+                                events.extend(
+                                    mouse_events
+                                        .into_iter()
+                                        .map(|event| (Some(id), event)),
+                                );
                             }
+
+                            events.extend(
+                                platform_specific_events
+                                    .into_iter()
+                                    .map(|event| (Some(id), event)),
+                            );
                         }
                     }
                     event::Event::AboutToWait => {
@@ -920,6 +993,13 @@ fn run_command<A, C, E>(
                 clipboard::Action::Write(contents, kind) => {
                     clipboard.write(kind, contents);
                 }
+                clipboard::Action::IsOwner(tag, kind) => {
+                    let message = tag(clipboard.is_owner(kind));
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
             },
             command::Action::Window(action) => match action {
                 window::Action::Spawn(id, settings) => {
@@ -959,6 +1039,13 @@ fn run_command<A, C, E>(
                         );
                     }
                 }
+                window::Action::ChangeAspectRatio(id, aspect_ratio) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window
+                            .state
+                            .set_aspect_ratio(&window.raw, aspect_ratio);
+                    }
+                }
                 window::Action::FetchSize(id, callback) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         let size = window
@@ -1027,6 +1114,20 @@ fn run_command<A, C, E>(
                         );
                     }
                 }
+                window::Action::SetImeCursorArea(id, area) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_ime_cursor_area(
+                            winit::dpi::LogicalPosition {
+                                x: area.x,
+                                y: area.y,
+                            },
+                            winit::dpi::LogicalSize {
+                                width: area.width,
+                                height: area.height,
+                            },
+                        );
+                    }
+                }
                 window::Action::ChangeMode(id, mode) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         window.raw.set_visible(conversion::visible(mode));
@@ -1054,6 +1155,13 @@ fn run_command<A, C, E>(
                             .expect("Send message to event loop");
                     }
                 }
+                window::Action::FetchPressedKeys(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        proxy
+                            .send_event(tag(window.state.pressed_keys()))
+                            .expect("Send message to event loop");
+                    }
+                }
                 window::Action::ToggleMaximize(id) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         window.raw.set_maximized(!window.raw.is_maximized());
@@ -1097,6 +1205,16 @@ fn run_command<A, C, E>(
                         }
                     }
                 }
+                window::Action::ShowSystemMenuAt(id, position) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.show_window_menu(
+                            winit::dpi::LogicalPosition {
+                                x: position.x,
+                                y: position.y,
+                            },
+                        );
+                    }
+                }
                 window::Action::FetchId(id, tag) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         proxy
@@ -1104,6 +1222,11 @@ fn run_command<A, C, E>(
                             .expect("Send message to event loop");
                     }
                 }
+                window::Action::FetchAll(tag) => {
+                    proxy
+                        .send_event(tag(window_manager.ids().collect()))
+                        .expect("Send message to event loop");
+                }
                 window::Action::RunWithHandle(id, tag) => {
                     use window::raw_window_handle::HasWindowHandle;
 
@@ -1116,6 +1239,16 @@ fn run_command<A, C, E>(
                             .expect("Send message to event loop");
                     }
                 }
+                window::Action::EnableMousePassthrough(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let _ = window.raw.set_cursor_hittest(false);
+                    }
+                }
+                window::Action::DisableMousePassthrough(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let _ = window.raw.set_cursor_hittest(true);
+                    }
+                }
                 window::Action::Screenshot(id, tag) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         let bytes = compositor.screenshot(
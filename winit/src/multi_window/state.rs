@@ -1,5 +1,5 @@
 use crate::conversion;
-use crate::core::{mouse, window};
+use crate::core::{keyboard, mouse, window};
 use crate::core::{Color, Size};
 use crate::graphics::Viewport;
 use crate::multi_window::{self, Application};
@@ -19,6 +19,8 @@ where
     viewport_version: u64,
     cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
     modifiers: winit::keyboard::ModifiersState,
+    pressed_keys: Vec<u32>,
+    aspect_ratio: Option<f32>,
     theme: A::Theme,
     appearance: multi_window::Appearance,
 }
@@ -70,6 +72,8 @@ where
             viewport_version: 0,
             cursor_position: None,
             modifiers: winit::keyboard::ModifiersState::default(),
+            pressed_keys: Vec::new(),
+            aspect_ratio: None,
             theme,
             appearance,
         }
@@ -120,6 +124,15 @@ where
         self.modifiers
     }
 
+    /// Returns the keys currently held down in the window of the [`State`],
+    /// together with the current state of the modifier keys.
+    pub fn pressed_keys(&self) -> keyboard::PressedKeys {
+        keyboard::PressedKeys {
+            scancodes: self.pressed_keys.clone(),
+            modifiers: conversion::modifiers(self.modifiers),
+        }
+    }
+
     /// Returns the current theme of the [`State`].
     pub fn theme(&self) -> &A::Theme {
         &self.theme
@@ -135,6 +148,39 @@ where
         self.appearance.text_color
     }
 
+    /// Returns the aspect ratio the window of the [`State`] keeps while
+    /// resized, if any.
+    pub fn aspect_ratio(&self) -> Option<f32> {
+        self.aspect_ratio
+    }
+
+    /// Sets the aspect ratio the window of the [`State`] keeps while resized.
+    ///
+    /// The window is resized right away to match the new aspect ratio, unless
+    /// it is maximized or fullscreen.
+    pub fn set_aspect_ratio(
+        &mut self,
+        window: &Window,
+        aspect_ratio: Option<f32>,
+    ) {
+        self.aspect_ratio = aspect_ratio;
+
+        let is_resizable =
+            !window.is_maximized() && window.fullscreen().is_none();
+
+        if let Some(aspect_ratio) = aspect_ratio.filter(|_| is_resizable) {
+            let size = self.viewport.physical_size();
+
+            let _ = window.request_inner_size(
+                conversion::constrain_to_aspect_ratio(
+                    size,
+                    winit::dpi::PhysicalSize::new(size.width, size.height),
+                    aspect_ratio,
+                ),
+            );
+        }
+    }
+
     /// Processes the provided window event and updates the [`State`] accordingly.
     pub fn update(
         &mut self,
@@ -142,8 +188,27 @@ where
         event: &WindowEvent,
         _debug: &mut crate::runtime::Debug,
     ) {
+        conversion::update_pressed_keys(&mut self.pressed_keys, event);
+
         match event {
             WindowEvent::Resized(new_size) => {
+                // The constrained size is only requested, since the window
+                // manager may refuse it (e.g. for tiled windows). Maximized
+                // and fullscreen windows are left alone.
+                if let Some(aspect_ratio) = self.aspect_ratio {
+                    if !window.is_maximized() && window.fullscreen().is_none() {
+                        let constrained = conversion::constrain_to_aspect_ratio(
+                            self.viewport.physical_size(),
+                            *new_size,
+                            aspect_ratio,
+                        );
+
+                        if constrained != *new_size {
+                            let _ = window.request_inner_size(constrained);
+                        }
+                    }
+                }
+
                 let size = Size::new(new_size.width, new_size.height);
 
                 self.viewport = Viewport::with_physical_size(
@@ -73,6 +73,10 @@ where
         self.entries.is_empty()
     }
 
+    pub fn ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.entries.keys().copied()
+    }
+
     pub fn iter_mut(
         &mut self,
     ) -> impl Iterator<Item = (Id, &mut Window<A, C>)> {
@@ -4,7 +4,7 @@ use crate::core::window;
 use std::borrow::Cow;
 
 /// The settings of an application.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct Settings<Flags> {
     /// The identifier of the application.
     ///
@@ -22,4 +22,38 @@ pub struct Settings<Flags> {
 
     /// The fonts to load on boot.
     pub fonts: Vec<Cow<'static, [u8]>>,
+
+    /// Whether keys held down should repeat.
+    ///
+    /// When disabled, holding a key down produces a single key press. This
+    /// also stops focus navigation from repeating while `Tab` is held.
+    ///
+    /// By default, it is enabled.
+    pub key_repeat: bool,
+
+    /// Whether the primary touch point should also produce mouse events.
+    ///
+    /// This lets widgets that only handle the mouse work on a touchscreen.
+    /// Widgets that handle touch events already (e.g. a `Button`) may react
+    /// twice to the same interaction, so it should only be enabled when
+    /// needed.
+    ///
+    /// By default, it is disabled.
+    pub touch_as_mouse: bool,
+}
+
+impl<Flags> Default for Settings<Flags>
+where
+    Flags: Default,
+{
+    fn default() -> Self {
+        Self {
+            id: None,
+            window: window::Settings::default(),
+            flags: Flags::default(),
+            fonts: Vec::new(),
+            key_repeat: true,
+            touch_as_mouse: false,
+        }
+    }
 }